@@ -0,0 +1,33 @@
+//! Measures the time it takes to coalesce a blob of many small parts.
+//!
+//! Run with `cargo bench --bench coalesce`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use blob::Blob;
+
+/// The number of one-byte parts in the blob.
+const PARTS: usize = 10_000;
+
+/// The number of times the blob is coalesced.
+const ITERATIONS: u32 = 1_000;
+
+fn main() {
+    let parts: Vec<_> = (0..PARTS).map(|n| vec![n as u8]).collect();
+    let blob = Blob::new(parts, None);
+
+    assert_eq!(blob.to_vec().len(), PARTS);
+
+    let start = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        black_box(black_box(&blob).to_vec());
+    }
+
+    let elapsed = start.elapsed();
+    println!(
+        "coalesce {PARTS} one-byte parts: {:?} per iteration",
+        elapsed / ITERATIONS
+    );
+}
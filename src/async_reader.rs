@@ -0,0 +1,327 @@
+use std::io::{self, BufRead, Seek, SeekFrom};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncWrite, AsyncWriteExt, ReadBuf};
+
+use crate::reader::{READ_CHUNK_SIZE, YIELD_BUDGET};
+use crate::{Blob, BlobError, BlobOptions, BlobReader};
+
+/// A reader that implements `tokio::io::AsyncRead` and `tokio::io::AsyncSeek`
+/// over the bytes contained in a Blob.
+///
+/// The bytes of a blob are already in memory, so reads complete immediately.
+/// To keep the executor fair, the reader yields once for every 1MB that is
+/// read.
+///
+#[derive(Debug)]
+pub struct AsyncBlobReader<'a> {
+    inner: BlobReader<'a>,
+
+    /// The number of bytes that may be read before the next yield.
+    budget: usize,
+}
+
+impl<'a> AsyncBlobReader<'a> {
+    fn new(blob: &'a Blob) -> Self {
+        Self {
+            inner: blob.reader(),
+            budget: YIELD_BUDGET,
+        }
+    }
+}
+
+impl AsyncRead for AsyncBlobReader<'_> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = &mut *self;
+
+        // The budget has been exhausted. Schedule the task to be polled again
+        // and give other tasks a chance to run in the meantime.
+        if this.budget == 0 {
+            this.budget = YIELD_BUDGET;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+
+        // Unlike BlobReader, fill as much of the buffer as possible, since each
+        // poll has a cost of its own.
+        while buf.remaining() > 0 && this.budget > 0 {
+            let chunk = this.inner.fill_buf()?;
+
+            if chunk.is_empty() {
+                break;
+            }
+
+            let len = chunk.len().min(buf.remaining()).min(this.budget);
+
+            buf.put_slice(&chunk[..len]);
+            this.inner.consume(len);
+            this.budget -= len;
+        }
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncSeek for AsyncBlobReader<'_> {
+    fn start_seek(mut self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+        self.inner.seek(position).map(drop)
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        // Seeks complete as soon as they are started.
+        Poll::Ready(Ok(self.inner.position()))
+    }
+}
+
+impl Blob {
+    /// Returns a reader that implements `tokio::io::AsyncRead` and
+    /// `tokio::io::AsyncSeek` over the bytes contained in self.
+    ///
+    /// This allows a blob to be piped into any `tokio::io` consumer with
+    /// `tokio::io::copy` without coalescing the blob into a single buffer.
+    ///
+    pub fn async_reader(&self) -> AsyncBlobReader<'_> {
+        AsyncBlobReader::new(self)
+    }
+
+    /// Reads `reader` to the end into a new Blob.
+    ///
+    /// The bytes are stored in parts of at most 64KB, so they are never copied
+    /// into a single contiguous buffer. If `limit` is provided, reading stops
+    /// as soon as the total exceeds `limit` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `reader` fails. If the reader produces more than
+    /// `limit` bytes, an error of the kind `InvalidData` that wraps
+    /// `BlobError::TooLarge` is returned.
+    ///
+    pub async fn from_async_read<R>(
+        mut reader: R,
+        limit: Option<usize>,
+        opts: Option<BlobOptions>,
+    ) -> io::Result<Self>
+    where
+        R: AsyncRead + Unpin,
+    {
+        let limit = limit.unwrap_or(usize::MAX);
+        let mut parts = Vec::new();
+        let mut size = 0;
+
+        loop {
+            // Fill each part before starting the next one, so small reads do
+            // not produce small parts.
+            let mut part = Vec::with_capacity(READ_CHUNK_SIZE);
+
+            while part.len() < READ_CHUNK_SIZE {
+                let n = reader.read_buf(&mut part).await?;
+
+                if n == 0 {
+                    break;
+                }
+
+                size += n;
+
+                if size > limit {
+                    return Err(BlobError::TooLarge { limit }.into());
+                }
+            }
+
+            if part.is_empty() {
+                break;
+            }
+
+            let done = part.len() < READ_CHUNK_SIZE;
+
+            parts.push(part);
+
+            if done {
+                break;
+            }
+        }
+
+        Ok(Self::new(parts, opts))
+    }
+
+    /// Writes the bytes contained in self to `writer` and returns the number
+    /// of bytes written.
+    ///
+    /// This is the asynchronous counterpart of `write_to`. Each part in view is
+    /// written directly rather than coalesced.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error produced by `writer` other than `Interrupted`.
+    ///
+    pub async fn write_to_async<W>(&self, writer: &mut W) -> io::Result<u64>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        let mut written = 0;
+
+        for chunk in self.chunks() {
+            writer.write_all(chunk).await?;
+            written += chunk.len() as u64;
+        }
+
+        Ok(written)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::SeekFrom;
+    use std::pin::Pin;
+    use std::task::{Context, Poll, Waker};
+
+    use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt, AsyncWriteExt, ReadBuf};
+
+    use crate::reader::{READ_CHUNK_SIZE, YIELD_BUDGET};
+    use crate::{Blob, BlobError};
+
+    #[tokio::test]
+    async fn copy() {
+        let blob = Blob::new(vec![&b"ABC"[..], b"", b"DEFG", b"HI"], None);
+        let mut output = Vec::new();
+
+        tokio::io::copy(&mut blob.async_reader(), &mut output)
+            .await
+            .unwrap();
+
+        assert_eq!(output, blob.to_vec());
+
+        // Only the bytes in view are read.
+        let slice = blob.slice(2, Some(-1), None);
+        let mut output = Vec::new();
+
+        tokio::io::copy(&mut slice.async_reader(), &mut output)
+            .await
+            .unwrap();
+
+        assert_eq!(output, b"CDEFGH");
+    }
+
+    #[tokio::test]
+    async fn copy_large() {
+        let part: Vec<u8> = (0..=255).cycle().take(1000).collect();
+        let blob = Blob::new(vec![part; 3000], None);
+        let mut output = Vec::new();
+
+        tokio::io::copy(&mut blob.async_reader(), &mut output)
+            .await
+            .unwrap();
+
+        assert_eq!(output, blob.to_vec());
+    }
+
+    #[test]
+    fn yields() {
+        let blob = Blob::new(vec![vec![0; YIELD_BUDGET]; 2], None);
+        let mut reader = blob.async_reader();
+        let mut storage = vec![0; YIELD_BUDGET * 2];
+        let mut buf = ReadBuf::new(&mut storage);
+        let mut cx = Context::from_waker(Waker::noop());
+
+        // The first read consumes the entire budget and the second yields.
+        let poll = Pin::new(&mut reader).poll_read(&mut cx, &mut buf);
+        assert!(matches!(poll, Poll::Ready(Ok(()))));
+        assert_eq!(buf.filled().len(), YIELD_BUDGET);
+
+        let poll = Pin::new(&mut reader).poll_read(&mut cx, &mut buf);
+        assert!(poll.is_pending());
+
+        let poll = Pin::new(&mut reader).poll_read(&mut cx, &mut buf);
+        assert!(matches!(poll, Poll::Ready(Ok(()))));
+        assert_eq!(buf.filled().len(), YIELD_BUDGET * 2);
+    }
+
+    #[tokio::test]
+    async fn seek() {
+        let blob = Blob::new(vec![&b"ABC"[..], b"DEFG", b"HI"], None);
+        let mut reader = blob.async_reader();
+        let mut output = String::new();
+
+        assert_eq!(reader.seek(SeekFrom::Start(4)).await.unwrap(), 4);
+        assert_eq!(reader.stream_position().await.unwrap(), 4);
+
+        reader.read_to_string(&mut output).await.unwrap();
+        assert_eq!(output, "EFGHI");
+
+        assert_eq!(reader.seek(SeekFrom::End(-3)).await.unwrap(), 6);
+        output.clear();
+        reader.read_to_string(&mut output).await.unwrap();
+        assert_eq!(output, "GHI");
+
+        assert!(reader.seek(SeekFrom::Current(-20)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn from_async_read() {
+        let data: Vec<u8> = (0..=255).cycle().take(READ_CHUNK_SIZE * 2 + 100).collect();
+
+        // A reader that produces at most 1000 bytes per read.
+        let (reader, mut writer) = tokio::io::simplex(1000);
+        let source = data.clone();
+
+        let write = async move {
+            writer.write_all(&source).await.unwrap();
+            writer.shutdown().await.unwrap();
+        };
+
+        let (blob, ()) = tokio::join!(Blob::from_async_read(reader, None, None), write);
+        let blob = blob.unwrap();
+
+        // Small reads are accumulated into full parts.
+        assert_eq!(blob.to_vec(), data);
+        assert_eq!(blob.data.len(), 3);
+        assert_eq!(blob.data[0].len(), READ_CHUNK_SIZE);
+
+        let blob = Blob::from_async_read(&b""[..], None, None).await.unwrap();
+        assert_eq!(blob, Blob::empty());
+    }
+
+    #[tokio::test]
+    async fn from_async_read_limit() {
+        let data = [0; 100];
+
+        let blob = Blob::from_async_read(&data[..], Some(100), None).await;
+        assert_eq!(blob.unwrap().size(), 100);
+
+        let error = Blob::from_async_read(&data[..], Some(99), None)
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(
+            error.into_inner().unwrap().downcast_ref::<BlobError>(),
+            Some(&BlobError::TooLarge { limit: 99 })
+        );
+    }
+
+    #[tokio::test]
+    async fn write_to_async() {
+        let blob = Blob::new(vec![&b"ABC"[..], b"", b"DEFG", b"HI"], None);
+        let slice = blob.slice(1, Some(-1), None);
+
+        // A pipe that accepts at most 2 bytes per write.
+        let (mut reader, mut writer) = tokio::io::simplex(2);
+
+        let write = async move {
+            let written = slice.write_to_async(&mut writer).await.unwrap();
+            writer.shutdown().await.unwrap();
+            written
+        };
+
+        let mut output = Vec::new();
+        let (written, read) = tokio::join!(write, reader.read_to_end(&mut output));
+
+        assert_eq!(written, 7);
+        assert_eq!(read.unwrap(), 7);
+        assert_eq!(output, b"BCDEFGH");
+    }
+}
@@ -0,0 +1,251 @@
+use js_sys::{Array, ArrayBuffer, Promise, Reflect, TypeError, Uint8Array};
+use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::ReadableStream;
+
+use crate::{Blob, BlobOptions, BlobPart, LineEndings};
+
+/// A Blob that is exported to JavaScript as a class named `Blob`.
+///
+/// This mirrors the `Blob` interface of the WHATWG File API. JavaScript code
+/// can construct one with `new Blob(parts, options)` and read it with the same
+/// methods as a native Blob. Parts can be strings, `ArrayBuffer`s, or views of
+/// an `ArrayBuffer`, such as a `Uint8Array`.
+///
+#[wasm_bindgen(js_name = Blob)]
+#[derive(Debug, Clone)]
+pub struct JsBlob {
+    blob: Blob,
+}
+
+#[wasm_bindgen(js_class = Blob)]
+impl JsBlob {
+    /// Constructs a new Blob from an optional array of parts and an optional
+    /// options object with `type` and `endings` properties.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `TypeError` if a part has an unsupported type or the value of
+    /// `endings` is not "transparent" or "native".
+    ///
+    #[wasm_bindgen(constructor)]
+    pub fn new(parts: Option<Array>, options: JsValue) -> Result<JsBlob, JsValue> {
+        let opts = if options.is_undefined() || options.is_null() {
+            None
+        } else {
+            Some(blob_options(&options)?)
+        };
+
+        let parts = match parts {
+            Some(parts) => parts.iter().map(blob_part).collect::<Result<Vec<_>, _>>()?,
+            None => Vec::new(),
+        };
+
+        Ok(Self::from(Blob::from_parts(parts, opts)))
+    }
+
+    /// The size of the blob in bytes.
+    ///
+    #[wasm_bindgen(getter)]
+    pub fn size(&self) -> f64 {
+        self.blob.size() as f64
+    }
+
+    /// The Content-Type of the blob, or an empty string if it is unknown.
+    ///
+    #[wasm_bindgen(getter, js_name = type)]
+    pub fn ty(&self) -> String {
+        self.blob.ty().to_owned()
+    }
+
+    /// Returns a new Blob that contains the bytes of self from `start` to
+    /// `end` with the Content-Type `content_type`.
+    ///
+    pub fn slice(
+        &self,
+        start: Option<f64>,
+        end: Option<f64>,
+        content_type: Option<String>,
+    ) -> JsBlob {
+        // Like the `[Clamp] long long` arguments of `Blob.prototype.slice`,
+        // fractional values are truncated and NaN is treated as 0.
+        let start = start.map_or(0, |start| start as i64);
+        let end = end.map(|end| end as i64);
+
+        Self::from(self.blob.slice(start, end, content_type))
+    }
+
+    /// Returns a `Promise` that resolves to the text contained in self.
+    ///
+    /// Like `Blob.prototype.text`, the bytes are always decoded as UTF-8 and
+    /// each invalid sequence is replaced with U+FFFD.
+    ///
+    pub fn text(&self) -> Promise {
+        let text = String::from_utf8_lossy(&self.blob.coalesce_text()).into_owned();
+        Promise::resolve(&JsValue::from(text))
+    }
+
+    /// Returns a `Promise` that resolves to an `ArrayBuffer` that contains a
+    /// copy of the bytes in self.
+    ///
+    #[wasm_bindgen(js_name = arrayBuffer)]
+    pub fn array_buffer(&self) -> Promise {
+        Promise::resolve(&JsValue::from(self.blob.array_buffer_sync()))
+    }
+
+    /// Returns a `Promise` that resolves to a `Uint8Array` that contains a
+    /// copy of the bytes in self.
+    ///
+    pub fn bytes(&self) -> Promise {
+        Promise::resolve(&JsValue::from(self.blob.bytes_sync()))
+    }
+
+    /// Returns a `ReadableStream` of the bytes contained in self.
+    ///
+    pub fn stream(&self) -> ReadableStream {
+        self.blob.stream()
+    }
+}
+
+impl AsRef<Blob> for JsBlob {
+    #[inline]
+    fn as_ref(&self) -> &Blob {
+        &self.blob
+    }
+}
+
+impl From<Blob> for JsBlob {
+    #[inline]
+    fn from(blob: Blob) -> Self {
+        Self { blob }
+    }
+}
+
+impl From<JsBlob> for Blob {
+    #[inline]
+    fn from(blob: JsBlob) -> Self {
+        blob.blob
+    }
+}
+
+/// Converts the options object passed to the Blob constructor.
+///
+fn blob_options(options: &JsValue) -> Result<BlobOptions, JsValue> {
+    let ty = Reflect::get(options, &JsValue::from_str("type"))?;
+    let endings = Reflect::get(options, &JsValue::from_str("endings"))?;
+
+    let endings = match endings.as_string().as_deref() {
+        None if endings.is_undefined() => LineEndings::Transparent,
+        Some("transparent") => LineEndings::Transparent,
+        Some("native") => LineEndings::Native,
+        _ => {
+            return Err(TypeError::new("endings must be \"transparent\" or \"native\"").into());
+        }
+    };
+
+    Ok(BlobOptions::new(endings, ty.as_string().map(Into::into)))
+}
+
+/// Converts a single element of the array of parts passed to the Blob
+/// constructor.
+///
+fn blob_part(part: JsValue) -> Result<BlobPart, JsValue> {
+    if let Some(text) = part.as_string() {
+        return Ok(BlobPart::Str(text));
+    }
+
+    if let Some(buffer) = part.dyn_ref::<ArrayBuffer>() {
+        return Ok(BlobPart::from(buffer));
+    }
+
+    if ArrayBuffer::is_view(&part) {
+        // Every view of an ArrayBuffer has the same buffer, byteOffset, and
+        // byteLength properties. Copy the bytes in view rather than the
+        // elements, which may be wider than a byte.
+        let view = part.unchecked_ref::<Uint8Array>();
+        let bytes = Uint8Array::new_with_byte_offset_and_length(
+            &view.buffer(),
+            view.byte_offset(),
+            view.byte_length(),
+        );
+
+        return Ok(BlobPart::from(bytes));
+    }
+
+    Err(
+        TypeError::new("blob parts must be strings, ArrayBuffers, or views of an ArrayBuffer")
+            .into(),
+    )
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use js_sys::{Array, ArrayBuffer, Object, Reflect, Uint16Array, Uint8Array};
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_futures::JsFuture;
+
+    use super::JsBlob;
+
+    fn options(ty: &str, endings: &str) -> JsValue {
+        let options = Object::new();
+
+        Reflect::set(&options, &"type".into(), &ty.into()).unwrap();
+        Reflect::set(&options, &"endings".into(), &endings.into()).unwrap();
+        options.into()
+    }
+
+    async fn text(blob: &JsBlob) -> String {
+        JsFuture::from(blob.text())
+            .await
+            .unwrap()
+            .as_string()
+            .unwrap()
+    }
+
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    async fn construct() {
+        let parts = Array::new();
+
+        parts.push(&"one\r\n".into());
+        parts.push(&Uint8Array::from(&b"two"[..]));
+        parts.push(&Uint8Array::from(&b" three"[..]).buffer());
+
+        let blob = JsBlob::new(Some(parts), options("text/plain", "transparent")).unwrap();
+
+        assert_eq!(blob.size(), 14.0);
+        assert_eq!(blob.ty(), "text/plain");
+        assert_eq!(text(&blob).await, "one\r\ntwo three");
+
+        let slice = blob.slice(Some(5.0), Some(-6.0), None);
+        assert_eq!(text(&slice).await, "two");
+        assert_eq!(slice.ty(), "");
+
+        let buffer = JsFuture::from(slice.array_buffer()).await.unwrap();
+        assert_eq!(Uint8Array::new(&buffer).to_vec(), b"two");
+    }
+
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    async fn construct_views() {
+        // Only the bytes in view of a typed array are copied.
+        let buffer = ArrayBuffer::new(8);
+        Uint8Array::new(&buffer).copy_from(b"ABCDEFGH");
+
+        let parts = Array::of1(&Uint16Array::new_with_byte_offset_and_length(&buffer, 2, 2));
+        let blob = JsBlob::new(Some(parts), JsValue::UNDEFINED).unwrap();
+
+        assert_eq!(text(&blob).await, "CDEF");
+        assert_eq!(
+            text(&JsBlob::new(None, JsValue::UNDEFINED).unwrap()).await,
+            ""
+        );
+    }
+
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    fn construct_invalid() {
+        let parts = Array::of1(&JsValue::from(1));
+        assert!(JsBlob::new(Some(parts), JsValue::UNDEFINED).is_err());
+
+        let options = options("", "unix");
+        assert!(JsBlob::new(None, options).is_err());
+    }
+}
@@ -0,0 +1,233 @@
+use crate::{Blob, BlobOptions, BlobPart, LineEndings};
+
+/// A builder for constructing a Blob incrementally from parts that arrive over
+/// time.
+///
+/// Parts are moved into the builder as they are pushed and are not copied
+/// again when the Blob is built.
+///
+#[derive(Debug, Default)]
+pub struct BlobBuilder {
+    endings: LineEndings,
+    parts: Vec<BlobPart>,
+    ty: Option<Box<str>>,
+}
+
+impl BlobBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a binary part. The bytes are stored as is.
+    ///
+    pub fn push_bytes(&mut self, bytes: Vec<u8>) -> &mut Self {
+        self.parts.push(BlobPart::Bytes(bytes));
+        self
+    }
+
+    /// Appends a string part. Unless the builder uses
+    /// `LineEndings::Transparent`, the line endings of the part are converted
+    /// when the Blob is built.
+    ///
+    pub fn push_str(&mut self, text: &str) -> &mut Self {
+        self.parts.push(BlobPart::Str(text.to_owned()));
+        self
+    }
+
+    /// Appends the bytes contained in `blob`. The bytes are shared with `blob`
+    /// rather than copied.
+    ///
+    pub fn push_blob(&mut self, blob: &Blob) -> &mut Self {
+        self.parts.push(BlobPart::Blob(blob.clone()));
+        self
+    }
+
+    /// Sets the line ending conversion that is applied to string parts.
+    ///
+    pub fn set_endings(&mut self, endings: LineEndings) -> &mut Self {
+        self.endings = endings;
+        self
+    }
+
+    /// Sets the Content-Type of the Blob.
+    ///
+    pub fn set_type(&mut self, ty: &str) -> &mut Self {
+        self.ty = Some(ty.into());
+        self
+    }
+
+    /// Consumes the builder and returns a Blob that contains every part that
+    /// was pushed, in order.
+    ///
+    pub fn build(self) -> Blob {
+        let opts = BlobOptions::new(self.endings, self.ty);
+        Blob::from_parts(self.parts, Some(opts))
+    }
+}
+
+/// A builder for BlobOptions.
+///
+/// The Content-Type is accepted as any string type and converted to the boxed
+/// representation that BlobOptions stores when the options are built.
+///
+#[derive(Debug, Default, Clone)]
+pub struct BlobOptionsBuilder {
+    endings: LineEndings,
+    strip_bom: bool,
+    ty: Option<String>,
+}
+
+impl BlobOptionsBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the line ending conversion that is applied to string parts.
+    ///
+    #[inline]
+    pub fn endings(mut self, endings: LineEndings) -> Self {
+        self.endings = endings;
+        self
+    }
+
+    /// Sets whether a leading byte order mark is stripped when the blob is
+    /// read as text.
+    ///
+    #[inline]
+    pub fn strip_bom(mut self, strip_bom: bool) -> Self {
+        self.strip_bom = strip_bom;
+        self
+    }
+
+    /// Sets the Content-Type of the blob.
+    ///
+    #[inline]
+    pub fn ty(mut self, ty: impl Into<String>) -> Self {
+        self.ty = Some(ty.into());
+        self
+    }
+
+    /// Returns BlobOptions with the configured values. The Content-Type is
+    /// validated the same way as `BlobOptions::new`.
+    ///
+    pub fn build(self) -> BlobOptions {
+        let ty = self.ty.map(String::into_boxed_str);
+        BlobOptions::new(self.endings, ty).with_strip_bom(self.strip_bom)
+    }
+}
+
+impl Extend<Vec<u8>> for BlobBuilder {
+    fn extend<I: IntoIterator<Item = Vec<u8>>>(&mut self, iter: I) {
+        self.parts.extend(iter.into_iter().map(BlobPart::Bytes));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{Blob, BlobOptions, LineEndings};
+
+    #[tokio::test]
+    async fn push_many() {
+        let mut builder = Blob::builder();
+        let mut expected = String::new();
+
+        for index in 0..1000 {
+            let part = format!("{index},");
+
+            if index % 2 == 0 {
+                builder.push_str(&part);
+            } else {
+                builder.push_bytes(part.clone().into_bytes());
+            }
+
+            expected.push_str(&part);
+        }
+
+        let blob = builder.build();
+
+        assert_eq!(blob.data.len(), 1000);
+        assert_eq!(blob.size(), expected.len());
+        assert_eq!(blob.text().await.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn push_blob() {
+        let source = Blob::new(vec![&b"ABC"[..], b"DEF"], None);
+        let mut builder = Blob::builder();
+
+        builder
+            .push_str("<")
+            .push_blob(&source.slice(2, Some(5), None))
+            .push_str(">");
+
+        let blob = builder.build();
+
+        assert_eq!(blob.text().await.unwrap(), "<CDE>");
+        assert!(Arc::ptr_eq(&blob.data[1].bytes, &source.data[0].bytes));
+    }
+
+    #[tokio::test]
+    async fn options() {
+        let mut builder = Blob::builder();
+
+        // The endings apply to string parts pushed before they were set.
+        builder.push_str("a\r\nb").push_bytes(b"\r\n".to_vec());
+        builder
+            .set_endings(LineEndings::Native)
+            .set_type("Text/Plain");
+
+        let blob = builder.build();
+
+        assert_eq!(blob.ty(), "text/plain");
+
+        #[cfg(target_os = "windows")]
+        assert_eq!(blob.text().await.unwrap(), "a\r\nb\r\n");
+
+        #[cfg(not(target_os = "windows"))]
+        assert_eq!(blob.text().await.unwrap(), "a\nb\r\n");
+    }
+
+    #[tokio::test]
+    async fn extend() {
+        let mut builder = Blob::builder();
+
+        builder.extend(vec![b"one ".to_vec(), b"two ".to_vec()]);
+        builder.extend((0..3).map(|n| n.to_string().into_bytes()));
+
+        assert_eq!(builder.build().text().await.unwrap(), "one two 012");
+    }
+
+    #[tokio::test]
+    async fn options_builder() {
+        let opts = BlobOptions::builder()
+            .ty(String::from("Text/Plain"))
+            .endings(LineEndings::Crlf)
+            .build();
+
+        let blob = Blob::from_strings(vec!["a\nb"], Some(opts));
+
+        assert_eq!(blob.ty(), "text/plain");
+        assert_eq!(blob.text().await.unwrap(), "a\r\nb");
+
+        // The defaults match BlobOptions::default.
+        let opts = BlobOptions::builder().build();
+        let blob = Blob::from_strings(vec!["a\nb"], Some(opts));
+
+        assert_eq!(blob.ty(), "");
+        assert_eq!(blob.text().await.unwrap(), "a\nb");
+
+        // Invalid types are discarded.
+        let opts = BlobOptions::builder()
+            .ty("caf\u{e9}")
+            .strip_bom(true)
+            .build();
+        let blob = Blob::new(vec![&b"\xEF\xBB\xBFx"[..]], Some(opts));
+
+        assert_eq!(blob.ty(), "");
+        assert_eq!(blob.text().await.unwrap(), "x");
+    }
+}
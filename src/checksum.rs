@@ -0,0 +1,123 @@
+use crate::Blob;
+
+/// The reflected polynomial of the CRC-32 used by gzip, PNG, and zip.
+const CRC32_POLYNOMIAL: u32 = 0xEDB8_8320;
+
+/// The CRC-32 of every byte value, computed at compile time.
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut index = 0;
+
+    while index < 256 {
+        let mut crc = index as u32;
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ CRC32_POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+
+        table[index] = crc;
+        index += 1;
+    }
+
+    table
+}
+
+impl Blob {
+    /// Returns the CRC-32 checksum of the bytes contained in self, as used by
+    /// gzip, PNG, and zip.
+    ///
+    /// The bytes are read one part at a time and never coalesced.
+    ///
+    pub fn crc32(&self) -> u32 {
+        let mut crc = !0u32;
+
+        for chunk in self.chunks() {
+            for &byte in chunk {
+                crc = (crc >> 8) ^ CRC32_TABLE[usize::from((crc as u8) ^ byte)];
+            }
+        }
+
+        !crc
+    }
+
+    /// Returns the SHA-256 digest of the bytes contained in self.
+    ///
+    /// The bytes are read one part at a time and never coalesced.
+    ///
+    #[cfg(feature = "hashing")]
+    pub fn sha256(&self) -> [u8; 32] {
+        use sha2::{Digest, Sha256};
+
+        let mut hasher = Sha256::new();
+
+        for chunk in self.chunks() {
+            hasher.update(chunk);
+        }
+
+        hasher.finalize().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Blob;
+
+    /// Splits `bytes` into parts of at most 3 bytes.
+    fn split(bytes: &[u8]) -> Blob {
+        Blob::new(bytes.chunks(3), None)
+    }
+
+    #[test]
+    fn crc32() {
+        assert_eq!(Blob::empty().crc32(), 0);
+        assert_eq!(Blob::from("123456789").crc32(), 0xCBF4_3926);
+        assert_eq!(
+            split(b"The quick brown fox jumps over the lazy dog").crc32(),
+            0x414F_A339
+        );
+
+        // The checksum of a slice matches the checksum of its coalesced bytes.
+        let blob = split(b"The quick brown fox jumps over the lazy dog").slice(4, Some(-4), None);
+        assert_eq!(blob.crc32(), Blob::from(blob.coalesce()).crc32());
+        assert_eq!(
+            blob.crc32(),
+            Blob::from("quick brown fox jumps over the lazy").crc32()
+        );
+    }
+
+    #[cfg(feature = "hashing")]
+    #[test]
+    fn sha256() {
+        fn hex(digest: [u8; 32]) -> String {
+            digest.iter().map(|byte| format!("{byte:02x}")).collect()
+        }
+
+        assert_eq!(
+            hex(Blob::empty().sha256()),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            hex(split(b"abc").sha256()),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+
+        let blob = split(b"The quick brown fox jumps over the lazy dog");
+
+        assert_eq!(
+            hex(blob.sha256()),
+            "d7a8fbb307d7809469ca9abcb0082e4f8d5651e46d3cdb762d02d0bf37c9e592"
+        );
+        assert_eq!(
+            blob.slice(4, Some(-4), None).sha256(),
+            Blob::from(blob.slice(4, Some(-4), None).coalesce()).sha256()
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use std::io::{self, Read};
+
+use flate2::read::{GzDecoder, ZlibDecoder};
+use flate2::write::{GzEncoder, ZlibEncoder};
+use flate2::Compression;
+
+use crate::Blob;
+
+/// A compression format supported by `Blob::compress` and `Blob::decompress`.
+///
+/// The formats correspond to those of the JavaScript `CompressionStream` and
+/// the `gzip` and `deflate` HTTP content codings.
+///
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Encoding {
+    /// The gzip file format defined in RFC 1952.
+    Gzip,
+
+    /// The zlib format defined in RFC 1950, which wraps a DEFLATE stream.
+    Deflate,
+}
+
+impl Blob {
+    /// Returns a new Blob that contains the bytes of self compressed with
+    /// `encoding`.
+    ///
+    /// The bytes of self are streamed into the encoder part by part rather
+    /// than coalesced first. The returned Blob does not have a Content-Type.
+    /// Callers that need one can set it with `slice`.
+    ///
+    pub fn compress(&self, encoding: Encoding) -> Blob {
+        let output = Vec::with_capacity(self.size() / 2);
+        let level = Compression::default();
+
+        let compressed = match encoding {
+            Encoding::Gzip => {
+                let mut encoder = GzEncoder::new(output, level);
+                io::copy(&mut self.reader(), &mut encoder).and_then(|_| encoder.finish())
+            }
+            Encoding::Deflate => {
+                let mut encoder = ZlibEncoder::new(output, level);
+                io::copy(&mut self.reader(), &mut encoder).and_then(|_| encoder.finish())
+            }
+        };
+
+        // Reading from a blob and writing to a Vec can not fail.
+        Blob::from(compressed.expect("compressing a blob into a Vec<u8> is infallible"))
+    }
+
+    /// Returns a new Blob that contains the bytes of self decompressed with
+    /// `encoding`. The returned Blob does not have a Content-Type.
+    ///
+    /// # Errors
+    ///
+    /// If the bytes of self are not a valid stream of the requested format.
+    ///
+    pub fn decompress(&self, encoding: Encoding) -> io::Result<Blob> {
+        let mut output = Vec::new();
+
+        match encoding {
+            Encoding::Gzip => GzDecoder::new(self.reader()).read_to_end(&mut output)?,
+            Encoding::Deflate => ZlibDecoder::new(self.reader()).read_to_end(&mut output)?,
+        };
+
+        Ok(Blob::from(output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Encoding;
+    use crate::Blob;
+
+    #[test]
+    fn round_trip() {
+        let line = "The quick brown fox jumps over the lazy dog.\n";
+        let parts: Vec<_> = (0..100).map(|_| line.as_bytes().to_vec()).collect();
+        let blob = Blob::new(parts, None);
+
+        for encoding in [Encoding::Gzip, Encoding::Deflate] {
+            let compressed = blob.compress(encoding);
+
+            assert!(compressed.size() < blob.size() / 10);
+            assert_eq!(compressed.ty(), "");
+            assert_eq!(compressed.decompress(encoding).unwrap(), blob);
+        }
+
+        // Only the bytes in view of a slice are compressed.
+        let slice = blob.slice(4, Some(-5), None);
+        let decompressed = slice.compress(Encoding::Gzip).decompress(Encoding::Gzip);
+
+        assert_eq!(decompressed.unwrap(), slice);
+    }
+
+    #[test]
+    fn decompress_invalid() {
+        let blob = Blob::from("not compressed");
+
+        assert!(blob.decompress(Encoding::Gzip).is_err());
+        assert!(blob.decompress(Encoding::Deflate).is_err());
+
+        // A gzip stream is not a valid zlib stream.
+        let gzip = blob.compress(Encoding::Gzip);
+        assert!(gzip.decompress(Encoding::Deflate).is_err());
+    }
+
+    #[test]
+    fn empty() {
+        let compressed = Blob::empty().compress(Encoding::Gzip);
+
+        assert!(compressed.size() > 0);
+        assert_eq!(compressed.decompress(Encoding::Gzip).unwrap().size(), 0);
+    }
+}
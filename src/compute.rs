@@ -0,0 +1,185 @@
+use std::io::{self, Read, Write};
+
+use fastly::http::header::CONTENT_TYPE;
+use fastly::{Body, Response};
+
+use crate::{Blob, BlobOptions, LineEndings};
+
+/// The maximum number of bytes that are read into each part by `from_body`.
+const BODY_CHUNK_SIZE: usize = 64 * 1024;
+
+impl Blob {
+    /// Reads a Fastly Compute `Body` into a new Blob with an optional
+    /// Content-Type.
+    ///
+    /// The body is read in chunks of at most 64KB and each chunk is stored as
+    /// a separate part, so the body is never buffered contiguously.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the body can not be read.
+    ///
+    pub fn from_body(body: Body, ty: Option<&str>) -> io::Result<Self> {
+        let parts = read_parts(body, BODY_CHUNK_SIZE)?;
+        let opts = BlobOptions::new(LineEndings::Transparent, ty.map(Into::into));
+        Ok(Self::new(parts, Some(opts)))
+    }
+}
+
+/// Writes the bytes contained in a Blob to a new Fastly Compute `Body`.
+///
+/// Each part in view is written separately rather than coalesced.
+///
+impl From<Blob> for Body {
+    fn from(blob: Blob) -> Self {
+        Self::from(&blob)
+    }
+}
+
+impl From<&Blob> for Body {
+    fn from(blob: &Blob) -> Self {
+        let mut body = Body::new();
+
+        // Writing to a new body only fails if the host call fails. Panic with
+        // the same message as the writers of the fastly crate.
+        write_parts(blob, &mut body).expect("fastly_http_body::write failed");
+
+        body
+    }
+}
+
+/// Responds with the bytes contained in a Blob.
+///
+/// The Content-Type header of the response is set to the type of the blob
+/// unless the type is unknown.
+///
+impl From<Blob> for Response {
+    fn from(blob: Blob) -> Self {
+        let mut response = Response::from_body(&blob);
+
+        if !blob.ty().is_empty() {
+            response.set_header(CONTENT_TYPE, blob.ty());
+        }
+
+        response
+    }
+}
+
+/// Reads `reader` to the end into parts of `chunk_size` bytes. Only the last
+/// part can be shorter.
+///
+fn read_parts(mut reader: impl Read, chunk_size: usize) -> io::Result<Vec<Vec<u8>>> {
+    let mut parts = Vec::new();
+
+    loop {
+        let mut part = Vec::with_capacity(chunk_size);
+
+        if reader
+            .by_ref()
+            .take(chunk_size as u64)
+            .read_to_end(&mut part)?
+            == 0
+        {
+            return Ok(parts);
+        }
+
+        parts.push(part);
+    }
+}
+
+/// Writes each part in view of `blob` to `writer` separately.
+///
+fn write_parts(blob: &Blob, mut writer: impl Write) -> io::Result<()> {
+    blob.chunks().try_for_each(|chunk| writer.write_all(chunk))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Read, Write};
+
+    use super::{read_parts, write_parts};
+    use crate::Blob;
+
+    /// A writer that records the buffer of each call to `write`.
+    #[derive(Default)]
+    struct Writes(Vec<Vec<u8>>);
+
+    impl Write for Writes {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.push(buf.to_vec());
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn read_chunks() {
+        let bytes: Vec<u8> = (0..=255).cycle().take(2500).collect();
+
+        // Short reads are combined into parts of the full chunk size.
+        let reader = io::Cursor::new(&bytes[..700]).chain(&bytes[700..]);
+        let parts = read_parts(reader, 1000).unwrap();
+
+        assert_eq!(
+            parts.iter().map(Vec::len).collect::<Vec<_>>(),
+            [1000, 1000, 500]
+        );
+        assert_eq!(parts.concat(), bytes);
+        assert!(read_parts(io::empty(), 1000).unwrap().is_empty());
+    }
+
+    #[test]
+    fn write_chunks() {
+        let blob = Blob::new(vec![&b"ABCD"[..], b"", b"EFGH", b"IJKL"], None);
+        let mut writes = Writes::default();
+
+        // Each part in view is written separately.
+        write_parts(&blob.slice(2, Some(-1), None), &mut writes).unwrap();
+        assert_eq!(writes.0, [&b"CD"[..], b"EFGH", b"IJK"]);
+    }
+
+    #[test]
+    fn round_trip() {
+        let parts: Vec<_> = (0..100u8).map(|n| vec![n; 1000]).collect();
+        let blob = Blob::new(parts, None).slice(500, Some(-500), None);
+        let mut bytes = Vec::new();
+
+        write_parts(&blob, &mut bytes).unwrap();
+
+        let decoded = Blob::new(read_parts(&bytes[..], 4096).unwrap(), None);
+        assert_eq!(decoded, blob);
+        assert_eq!(decoded.data.len(), 25);
+    }
+}
+
+#[cfg(all(test, target_os = "wasi"))]
+mod wasi_tests {
+    use fastly::http::header::CONTENT_TYPE;
+    use fastly::{Body, Response};
+
+    use crate::{Blob, BlobOptions, LineEndings};
+
+    #[test]
+    fn round_trip() {
+        let parts: Vec<_> = (0..100u8).map(|n| vec![n; 1000]).collect();
+        let blob = Blob::new(parts, None).slice(500, Some(-500), None);
+        let decoded = Blob::from_body(Body::from(&blob), Some("application/octet-stream")).unwrap();
+
+        assert_eq!(decoded.to_vec(), blob.to_vec());
+        assert_eq!(decoded.ty(), "application/octet-stream");
+        assert!(decoded.data.len() > 1);
+    }
+
+    #[test]
+    fn response() {
+        let opts = BlobOptions::new(LineEndings::Transparent, Some("text/plain".into()));
+        let blob = Blob::from_parts(vec!["hello, ", "world"], Some(opts));
+        let response = Response::from(blob);
+
+        assert_eq!(response.get_header_str(CONTENT_TYPE), Some("text/plain"));
+        assert_eq!(response.into_body_str(), "hello, world");
+    }
+}
@@ -0,0 +1,191 @@
+use crate::Blob;
+
+/// The alphabet of the standard base64 encoding defined in RFC 4648.
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// The Content-Type of a data URL for a blob that does not have one.
+const DEFAULT_TYPE: &str = "application/octet-stream";
+
+impl Blob {
+    /// Returns a `data:` URL that contains the base64 encoded bytes of self.
+    ///
+    /// The media type of the URL is the Content-Type of self, or
+    /// `application/octet-stream` if self does not have one. This matches the
+    /// output of `FileReader.prototype.readAsDataURL`.
+    ///
+    pub fn to_data_url(&self) -> String {
+        let ty = match self.ty() {
+            "" => DEFAULT_TYPE,
+            ty => ty,
+        };
+
+        let bytes = self.coalesce();
+        let mut url = String::with_capacity(ty.len() + 13 + bytes.len().div_ceil(3) * 4);
+
+        url.push_str("data:");
+        url.push_str(ty);
+        url.push_str(";base64,");
+        encode_base64(&bytes, &mut url);
+
+        url
+    }
+}
+
+/// Appends the padded base64 encoding of `bytes` to `output`.
+///
+pub(crate) fn encode_base64(bytes: &[u8], output: &mut String) {
+    let mut chunks = bytes.chunks_exact(3);
+
+    for chunk in &mut chunks {
+        let group = u32::from_be_bytes([0, chunk[0], chunk[1], chunk[2]]);
+
+        for shift in [18, 12, 6, 0] {
+            output.push(ALPHABET[(group >> shift) as usize & 0x3f] as char);
+        }
+    }
+
+    // Encode the last one or two bytes, if any, followed by padding so the
+    // output is always a multiple of four chars.
+    let rest = chunks.remainder();
+
+    if !rest.is_empty() {
+        let group = u32::from_be_bytes([0, rest[0], rest.get(1).copied().unwrap_or(0), 0]);
+
+        output.push(ALPHABET[(group >> 18) as usize & 0x3f] as char);
+        output.push(ALPHABET[(group >> 12) as usize & 0x3f] as char);
+
+        if rest.len() == 2 {
+            output.push(ALPHABET[(group >> 6) as usize & 0x3f] as char);
+        } else {
+            output.push('=');
+        }
+
+        output.push('=');
+    }
+}
+
+/// Decodes the padded base64 encoding of `input`.
+///
+/// Returns `None` if `input` is not a multiple of four chars long or contains a
+/// char that is not part of the alphabet.
+///
+#[cfg(feature = "serde")]
+pub(crate) fn decode_base64(input: &str) -> Option<Vec<u8>> {
+    if !input.len().is_multiple_of(4) {
+        return None;
+    }
+
+    // Padding is only valid at the very end of the input.
+    let trimmed = input.strip_suffix("==").or_else(|| input.strip_suffix('='));
+    let (input, padding) = match trimmed {
+        Some(trimmed) => (trimmed, input.len() - trimmed.len()),
+        None => (input, 0),
+    };
+
+    let mut output = Vec::with_capacity(input.len() / 4 * 3 + 2);
+    let mut group = 0u32;
+
+    for (index, byte) in input.bytes().enumerate() {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+
+        group = group << 6 | u32::from(value);
+
+        if index % 4 == 3 {
+            output.extend_from_slice(&group.to_be_bytes()[1..]);
+            group = 0;
+        }
+    }
+
+    // The last group contains two or three chars if the input was padded.
+    match padding {
+        1 => output.extend_from_slice(&(group << 6).to_be_bytes()[1..3]),
+        2 => output.push((group << 12).to_be_bytes()[1]),
+        _ => {}
+    }
+
+    Some(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::encode_base64;
+    use crate::{Blob, BlobOptions, LineEndings};
+
+    #[test]
+    fn base64() {
+        let encode = |input: &[u8]| {
+            let mut output = String::new();
+            encode_base64(input, &mut output);
+            output
+        };
+
+        // The test vectors from RFC 4648, section 10.
+        assert_eq!(encode(b""), "");
+        assert_eq!(encode(b"f"), "Zg==");
+        assert_eq!(encode(b"fo"), "Zm8=");
+        assert_eq!(encode(b"foo"), "Zm9v");
+        assert_eq!(encode(b"foob"), "Zm9vYg==");
+        assert_eq!(encode(b"fooba"), "Zm9vYmE=");
+        assert_eq!(encode(b"foobar"), "Zm9vYmFy");
+
+        // Every index of the alphabet, including the last two.
+        assert_eq!(encode(&[0x00, 0x10, 0x83]), "ABCD");
+        assert_eq!(encode(&[0xfb, 0xff, 0xbf]), "+/+/");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn base64_decode() {
+        use super::decode_base64;
+
+        for input in [
+            &b""[..],
+            b"f",
+            b"fo",
+            b"foo",
+            b"foob",
+            b"fooba",
+            b"foobar",
+            b"\xfb\xff\xbf",
+        ] {
+            let mut encoded = String::new();
+            encode_base64(input, &mut encoded);
+            assert_eq!(decode_base64(&encoded).as_deref(), Some(input));
+        }
+
+        assert_eq!(decode_base64("Zg="), None);
+        assert_eq!(decode_base64("Z==="), None);
+        assert_eq!(decode_base64("Zg=a"), None);
+        assert_eq!(decode_base64("Zm9v!A=="), None);
+    }
+
+    #[test]
+    fn to_data_url() {
+        let opts = BlobOptions::new(LineEndings::Transparent, Some("text/plain".into()));
+        let blob = Blob::new(vec![&b"Hello, "[..], b"World!"], Some(opts));
+
+        assert_eq!(
+            blob.to_data_url(),
+            "data:text/plain;base64,SGVsbG8sIFdvcmxkIQ=="
+        );
+
+        // The payload only includes the bytes in view of a slice.
+        assert_eq!(
+            blob.slice(5, Some(8), None).to_data_url(),
+            "data:application/octet-stream;base64,LCBX"
+        );
+
+        // Empty blobs still produce a valid URL.
+        assert_eq!(
+            Blob::empty().to_data_url(),
+            "data:application/octet-stream;base64,"
+        );
+    }
+}
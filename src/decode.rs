@@ -0,0 +1,263 @@
+use encoding_rs::{CoderResult, Encoding, UTF_8};
+
+use crate::{Blob, BlobError};
+
+impl Blob {
+    /// Returns a `Future` that resolves to the text contained in self, decoded
+    /// with the encoding identified by `label`.
+    ///
+    /// Labels are resolved the same way as the JavaScript `TextDecoder`, so
+    /// `"latin1"`, `"utf-16le"`, and `"shift_jis"` are all accepted. A leading
+    /// BOM of the requested encoding is stripped and malformed sequences are
+    /// replaced with U+FFFD. Prefer `text` for UTF-8 input.
+    ///
+    /// The parts of self are streamed through the decoder one at a time rather
+    /// than coalesced first, so a multi-byte sequence that is split across two
+    /// parts decodes normally. Line endings are converted when string parts
+    /// are stored, so the decoded text reflects the `endings` option of self
+    /// just like `text`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BlobError::UnknownEncoding` if `label` does not identify an
+    /// encoding defined by the WHATWG Encoding Standard.
+    ///
+    pub async fn text_with_encoding(&self, label: &str) -> Result<String, BlobError> {
+        let encoding = Encoding::for_label(label.as_bytes())
+            .ok_or_else(|| BlobError::UnknownEncoding(label.into()))?;
+
+        Ok(self.decode(encoding))
+    }
+}
+
+impl Blob {
+    /// Returns the encoding that `text` should decode self with if it is not
+    /// UTF-8.
+    ///
+    /// If self is configured to strip a BOM, a leading BOM takes precedence
+    /// over the charset parameter of the Content-Type of self.
+    ///
+    pub(crate) fn text_encoding(&self) -> Option<&'static Encoding> {
+        let encoding = self.bom_encoding().or_else(|| {
+            let label = self.charset()?;
+            Encoding::for_label(label.as_bytes())
+        });
+
+        encoding.filter(|&encoding| encoding != UTF_8)
+    }
+
+    /// Returns the encoding indicated by the BOM at the start of self, if self
+    /// is configured to strip a BOM.
+    ///
+    fn bom_encoding(&self) -> Option<&'static Encoding> {
+        if !self.opts.strip_bom {
+            return None;
+        }
+
+        // The longest BOM is 3 bytes. Read them through the iterator so that a
+        // BOM that is split across parts is detected.
+        let mut prefix = [0; 3];
+        let mut len = 0;
+
+        for (slot, byte) in prefix.iter_mut().zip(self.iter()) {
+            *slot = byte;
+            len += 1;
+        }
+
+        Encoding::for_bom(&prefix[..len]).map(|(encoding, _)| encoding)
+    }
+
+    /// Decodes the bytes of self with `encoding`, streaming each part through
+    /// the decoder.
+    ///
+    /// A leading BOM of `encoding` is always removed. If self is configured to
+    /// strip a BOM, any BOM is removed and overrides `encoding`.
+    ///
+    pub(crate) fn decode(&self, encoding: &'static Encoding) -> String {
+        let mut decoder = if self.opts.strip_bom {
+            encoding.new_decoder()
+        } else {
+            encoding.new_decoder_with_bom_removal()
+        };
+
+        let mut text = String::new();
+
+        let mut feed = |chunk: &[u8], last: bool| {
+            // Reserve enough space for the worst case so the decoder never
+            // reports that the output is full. The length only overflows for
+            // chunks that could not have been allocated in the first place.
+            if let Some(additional) = decoder.max_utf8_buffer_length(chunk.len()) {
+                text.reserve(additional);
+            }
+
+            let (result, _, _) = decoder.decode_to_string(chunk, &mut text, last);
+            debug_assert_eq!(result, CoderResult::InputEmpty);
+        };
+
+        for chunk in self.chunks() {
+            feed(chunk, false);
+        }
+
+        // Flush any incomplete sequence that is still buffered by the decoder.
+        feed(&[], true);
+
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Blob, BlobError, BlobOptions, LineEndings};
+
+    #[tokio::test]
+    async fn utf_16le() {
+        // "hi\u{20AC}" in UTF-16LE, prefixed with a BOM.
+        let blob = Blob::from(&b"\xFF\xFEh\x00i\x00\xAC\x20"[..]);
+
+        assert_eq!(
+            blob.text_with_encoding("utf-16le").await.unwrap(),
+            "hi\u{20AC}"
+        );
+        assert_eq!(
+            blob.slice(2, None, None)
+                .text_with_encoding("UTF-16LE")
+                .await
+                .unwrap(),
+            "hi\u{20AC}"
+        );
+    }
+
+    #[tokio::test]
+    async fn latin1() {
+        let blob = Blob::from(&b"caf\xE9 cr\xE8me"[..]);
+
+        assert!(blob.text().await.is_err());
+        assert_eq!(
+            blob.text_with_encoding("latin1").await.unwrap(),
+            "caf\u{e9} cr\u{e8}me"
+        );
+        assert_eq!(
+            blob.text_with_encoding(" iso-8859-1 ").await.unwrap(),
+            "caf\u{e9} cr\u{e8}me"
+        );
+    }
+
+    #[tokio::test]
+    async fn split_sequences() {
+        // A UTF-16LE BOM and code unit that are both split across parts.
+        let blob = Blob::new(
+            vec![&b"\xFF"[..], b"\xFEa", b"\x00\x3D\xD8", b"\x00\xDE"],
+            None,
+        );
+
+        assert_eq!(
+            blob.text_with_encoding("utf-16le").await.unwrap(),
+            "a\u{1F600}"
+        );
+
+        // "\u{65E5}\u{672C}" in Shift_JIS with the first character split.
+        let blob = Blob::new(vec![&b"\x93"[..], b"\xFA\x96\x7B"], None);
+
+        assert_eq!(
+            blob.text_with_encoding("shift_jis").await.unwrap(),
+            "\u{65E5}\u{672C}"
+        );
+
+        // A sequence cut short by the end of the view is replaced.
+        assert_eq!(
+            blob.slice(0, Some(3), None)
+                .text_with_encoding("sjis")
+                .await
+                .unwrap(),
+            "\u{65E5}\u{FFFD}"
+        );
+    }
+
+    #[tokio::test]
+    async fn endings() {
+        let opts = BlobOptions::new(LineEndings::Crlf, None);
+        let blob = Blob::from_strings(vec!["one\ntwo\n"], Some(opts));
+
+        assert_eq!(
+            blob.text_with_encoding("utf-8").await.unwrap(),
+            blob.text().await.unwrap()
+        );
+        assert_eq!(
+            blob.text_with_encoding("utf-8").await.unwrap(),
+            "one\r\ntwo\r\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn text_charset() {
+        let opts = |ty: &str| Some(BlobOptions::new(LineEndings::Transparent, Some(ty.into())));
+
+        // The charset parameter of the Content-Type selects the decoder.
+        let blob = Blob::new(
+            vec![&b"caf\xE9 \x80"[..]],
+            opts("text/plain; charset=windows-1252"),
+        );
+        assert_eq!(blob.text().await.unwrap(), "caf\u{e9} \u{20AC}");
+
+        let blob = Blob::new(vec![&b"caf\xE9"[..]], opts("text/plain;charset=\"Latin1\""));
+        assert_eq!(blob.text().await.unwrap(), "caf\u{e9}");
+
+        // UTF-8, unknown charsets, and a missing charset use the strict UTF-8
+        // decoder.
+        for ty in [
+            "text/plain",
+            "text/plain; charset=utf-8",
+            "text/plain; charset=bogus",
+        ] {
+            let blob = Blob::new(vec!["caf\u{e9}".as_bytes()], opts(ty));
+            assert_eq!(blob.text().await.unwrap(), "caf\u{e9}");
+
+            let blob = Blob::new(vec![&b"caf\xE9"[..]], opts(ty));
+            assert!(matches!(blob.text().await, Err(BlobError::InvalidUtf8(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn strip_bom() {
+        let opts = |ty: &str| {
+            let opts = BlobOptions::new(LineEndings::Transparent, Some(ty.into()));
+            Some(opts.with_strip_bom(true))
+        };
+
+        // A UTF-16LE BOM, split across parts, overrides the charset.
+        let blob = Blob::new(
+            vec![&b"\xFF"[..], b"\xFEh\x00i\x00"],
+            opts("text/plain; charset=latin1"),
+        );
+
+        assert_eq!(blob.text().await.unwrap(), "hi");
+        assert_eq!(blob.text_with_encoding("latin1").await.unwrap(), "hi");
+
+        // A UTF-8 BOM overrides a charset as well.
+        let blob = Blob::new(
+            vec!["\u{feff}caf\u{e9}".as_bytes()],
+            opts("text/plain; charset=latin1"),
+        );
+
+        assert_eq!(blob.text().await.unwrap(), "caf\u{e9}");
+
+        // Without the option, only a BOM of the requested encoding is removed.
+        let blob = Blob::new(vec![&b"\xFF\xFEh\x00"[..]], None);
+
+        assert_eq!(blob.text_with_encoding("utf-16le").await.unwrap(), "h");
+        assert_eq!(
+            blob.text_with_encoding("latin1").await.unwrap(),
+            "\u{ff}\u{fe}h\u{0}"
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_label() {
+        let blob = Blob::from("text");
+
+        assert_eq!(
+            blob.text_with_encoding("utf-9").await,
+            Err(BlobError::UnknownEncoding("utf-9".into()))
+        );
+    }
+}
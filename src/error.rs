@@ -0,0 +1,115 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::io;
+use std::string::FromUtf8Error;
+
+/// An error that can occur when reading the contents of a Blob.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BlobError {
+    /// The bytes of the blob are not a valid UTF-8 sequence.
+    InvalidUtf8(FromUtf8Error),
+
+    /// The range from `start` to `end` does not lie within a blob of `size`
+    /// bytes.
+    RangeError {
+        start: usize,
+        end: usize,
+        size: usize,
+    },
+
+    /// The label does not identify an encoding defined by the WHATWG Encoding
+    /// Standard.
+    UnknownEncoding(Box<str>),
+
+    /// The bytes being collected into a blob exceed the maximum size of
+    /// `limit` bytes.
+    TooLarge { limit: usize },
+
+    /// The Content-Type contains a character outside of printable ASCII.
+    InvalidType(Box<str>),
+}
+
+impl Display for BlobError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidUtf8(error) => write!(f, "blob is not valid UTF-8: {error}"),
+            Self::RangeError { start, end, size } => write!(
+                f,
+                "range {start}..{end} is out of bounds for a blob of {size} bytes"
+            ),
+            Self::UnknownEncoding(label) => write!(f, "unknown encoding label: {label:?}"),
+            Self::TooLarge { limit } => {
+                write!(f, "blob exceeds the maximum size of {limit} bytes")
+            }
+            Self::InvalidType(ty) => write!(f, "invalid content type: {ty:?}"),
+        }
+    }
+}
+
+impl Error for BlobError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidUtf8(error) => Some(error),
+            Self::RangeError { .. }
+            | Self::UnknownEncoding(_)
+            | Self::TooLarge { .. }
+            | Self::InvalidType(_) => None,
+        }
+    }
+}
+
+impl From<FromUtf8Error> for BlobError {
+    #[inline]
+    fn from(error: FromUtf8Error) -> Self {
+        Self::InvalidUtf8(error)
+    }
+}
+
+impl From<BlobError> for io::Error {
+    #[inline]
+    fn from(error: BlobError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use super::BlobError;
+    use crate::Blob;
+
+    #[tokio::test]
+    async fn invalid_utf8() {
+        let blob = Blob::new(vec![&b"valid "[..], b"\xC3\x28"], None);
+        let error = blob.text().await.unwrap_err();
+
+        match &error {
+            BlobError::InvalidUtf8(inner) => {
+                assert_eq!(inner.utf8_error().valid_up_to(), 6);
+                assert_eq!(inner.as_bytes(), b"valid \xC3\x28");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+
+        assert!(error.source().is_some());
+        assert!(error.to_string().starts_with("blob is not valid UTF-8"));
+    }
+
+    #[test]
+    fn range_error() {
+        let error = BlobError::RangeError {
+            start: 4,
+            end: 12,
+            size: 8,
+        };
+
+        assert!(error.source().is_none());
+        assert_eq!(
+            error.to_string(),
+            "range 4..12 is out of bounds for a blob of 8 bytes"
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use std::ops::Deref;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{Blob, BlobOptions, BlobPart};
+
+/// The MIME types of common file extensions, including double extensions
+/// such as `tar.gz`.
+///
+const EXTENSIONS: &[(&str, &str)] = &[
+    ("css", "text/css"),
+    ("csv", "text/csv"),
+    ("gif", "image/gif"),
+    ("gz", "application/gzip"),
+    ("htm", "text/html"),
+    ("html", "text/html"),
+    ("jpeg", "image/jpeg"),
+    ("jpg", "image/jpeg"),
+    ("js", "text/javascript"),
+    ("json", "application/json"),
+    ("md", "text/markdown"),
+    ("mjs", "text/javascript"),
+    ("mp4", "video/mp4"),
+    ("pdf", "application/pdf"),
+    ("png", "image/png"),
+    ("svg", "image/svg+xml"),
+    ("tar", "application/x-tar"),
+    ("tar.gz", "application/gzip"),
+    ("tgz", "application/gzip"),
+    ("txt", "text/plain"),
+    ("wasm", "application/wasm"),
+    ("wav", "audio/wav"),
+    ("webp", "image/webp"),
+    ("xml", "application/xml"),
+    ("zip", "application/zip"),
+];
+
+/// Returns the MIME type of a file named `name`, guessed from its extension.
+///
+/// Extensions are matched without regard to case, and the longest matching
+/// extension wins, so `archive.tar.gz` is `application/gzip` while
+/// `archive.tar` is `application/x-tar`. A name that is nothing but an
+/// extension, like `.json`, does not match.
+///
+pub fn guess_type(name: &str) -> Option<&'static str> {
+    let name = name.as_bytes();
+
+    EXTENSIONS
+        .iter()
+        .filter(|(ext, _)| {
+            let ext = ext.as_bytes();
+            let Some(dot) = name.len().checked_sub(ext.len() + 1) else {
+                return false;
+            };
+
+            dot > 0 && name[dot] == b'.' && name[dot + 1..].eq_ignore_ascii_case(ext)
+        })
+        .max_by_key(|(ext, _)| ext.len())
+        .map(|(_, ty)| *ty)
+}
+
+/// A Blob with a file name and a last modified timestamp.
+///
+/// This mirrors the `File` interface of the WHATWG File API. A File derefs to
+/// the Blob that contains its bytes, so it can be used anywhere a Blob can.
+///
+#[derive(Debug, Clone)]
+pub struct File {
+    blob: Blob,
+    name: Box<str>,
+
+    /// The number of milliseconds since the Unix epoch.
+    last_modified: i64,
+}
+
+impl File {
+    /// Constructs a new File instance from a sequence of parts and a file name.
+    ///
+    /// If `opts` does not provide a type, it is guessed from the extension of
+    /// `name` with `guess_type`. The last modified timestamp defaults to the
+    /// current time. Use `with_last_modified` to provide a different value.
+    ///
+    pub fn new<I>(parts: I, name: &str, opts: Option<BlobOptions>) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<BlobPart>,
+    {
+        Self::from_blob(Blob::from_parts(parts, opts), name)
+    }
+
+    /// Constructs a new File instance that shares the bytes of `blob`.
+    ///
+    /// If the type of `blob` is empty, it is guessed from the extension of
+    /// `name` with `guess_type`.
+    ///
+    pub fn from_blob(blob: Blob, name: &str) -> Self {
+        let blob = match guess_type(name) {
+            Some(ty) if blob.ty().is_empty() => blob.slice(0, None, Some(ty.to_owned())),
+            _ => blob,
+        };
+
+        Self {
+            blob,
+            name: name.into(),
+            last_modified: now(),
+        }
+    }
+
+    /// Sets the last modified timestamp of self to `last_modified`, expressed
+    /// in milliseconds since the Unix epoch.
+    ///
+    #[inline]
+    pub fn with_last_modified(mut self, last_modified: i64) -> Self {
+        self.last_modified = last_modified;
+        self
+    }
+
+    /// Sets the last modified timestamp of self to `last_modified`.
+    ///
+    /// The timestamp is stored in whole milliseconds, like `lastModified` in
+    /// JavaScript, so any finer precision is truncated. Times that are out of
+    /// the range of an `i64` of milliseconds are clamped.
+    ///
+    pub fn with_last_modified_time(self, last_modified: SystemTime) -> Self {
+        let millis = match last_modified.duration_since(UNIX_EPOCH) {
+            Ok(elapsed) => i64::try_from(elapsed.as_millis()).unwrap_or(i64::MAX),
+            Err(error) => i64::try_from(error.duration().as_millis()).map_or(i64::MIN, |ms| -ms),
+        };
+
+        self.with_last_modified(millis)
+    }
+
+    /// Returns the name of the file.
+    ///
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the last modified timestamp of the file in milliseconds since
+    /// the Unix epoch.
+    ///
+    #[inline]
+    pub fn last_modified(&self) -> i64 {
+        self.last_modified
+    }
+
+    /// Returns the last modified timestamp of the file as a `SystemTime`.
+    ///
+    pub fn last_modified_time(&self) -> SystemTime {
+        let elapsed = Duration::from_millis(self.last_modified.unsigned_abs());
+
+        if self.last_modified < 0 {
+            UNIX_EPOCH - elapsed
+        } else {
+            UNIX_EPOCH + elapsed
+        }
+    }
+
+    /// Consumes self and returns the Blob that contains the bytes of the file.
+    ///
+    #[inline]
+    pub fn into_blob(self) -> Blob {
+        self.blob
+    }
+}
+
+impl AsRef<Blob> for File {
+    #[inline]
+    fn as_ref(&self) -> &Blob {
+        &self.blob
+    }
+}
+
+impl Deref for File {
+    type Target = Blob;
+
+    #[inline]
+    fn deref(&self) -> &Blob {
+        &self.blob
+    }
+}
+
+impl From<File> for Blob {
+    #[inline]
+    fn from(file: File) -> Self {
+        file.blob
+    }
+}
+
+/// Returns the current time in milliseconds since the Unix epoch.
+///
+#[cfg(all(target_arch = "wasm32", feature = "js"))]
+fn now() -> i64 {
+    js_sys::Date::now() as i64
+}
+
+/// Returns the current time in milliseconds since the Unix epoch.
+///
+/// The system clock is not available on wasm targets without JavaScript, so
+/// the epoch itself is used instead.
+///
+#[cfg(not(all(target_arch = "wasm32", feature = "js")))]
+fn now() -> i64 {
+    #[cfg(target_arch = "wasm32")]
+    return 0;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{guess_type, File};
+    use crate::{Blob, BlobOptions, LineEndings};
+
+    #[tokio::test]
+    async fn new() {
+        let opts = BlobOptions::new(LineEndings::Transparent, Some("text/plain".into()));
+        let file = File::new(vec!["hello, ", "world"], "hello.txt", Some(opts))
+            .with_last_modified(1_700_000_000_000);
+
+        assert_eq!(file.name(), "hello.txt");
+        assert_eq!(file.last_modified(), 1_700_000_000_000);
+        assert_eq!(file.ty(), "text/plain");
+        assert_eq!(file.size(), 12);
+        assert_eq!(file.text().await.unwrap(), "hello, world");
+
+        // Slicing a file returns a plain Blob.
+        let slice: Blob = file.slice(7, None, None);
+        assert_eq!(slice.text().await.unwrap(), "world");
+    }
+
+    #[test]
+    fn guess() {
+        let names = [
+            ("data.json", Some("application/json")),
+            ("logo.png", Some("image/png")),
+            ("module.wasm", Some("application/wasm")),
+            ("PHOTO.JPG", Some("image/jpeg")),
+            ("index.Html", Some("text/html")),
+            ("archive.tar.gz", Some("application/gzip")),
+            ("ARCHIVE.TAR.GZ", Some("application/gzip")),
+            ("archive.tar", Some("application/x-tar")),
+            ("report.final.pdf", Some("application/pdf")),
+            ("notes.txt.bak", None),
+            ("README", None),
+            (".json", None),
+            ("json", None),
+            ("trailing.", None),
+            ("", None),
+        ];
+
+        for (name, ty) in names {
+            assert_eq!(guess_type(name), ty, "{name:?}");
+        }
+    }
+
+    #[test]
+    fn new_guess_type() {
+        let file = File::new(vec!["{}"], "data.json", None);
+        assert_eq!(file.ty(), "application/json");
+
+        // An explicit type is never replaced.
+        let opts = BlobOptions::new(LineEndings::Transparent, Some("text/plain".into()));
+        let file = File::new(vec!["{}"], "data.json", Some(opts));
+        assert_eq!(file.ty(), "text/plain");
+
+        let file = File::from_blob(Blob::from("{}"), "DATA.JSON");
+        assert_eq!(file.ty(), "application/json");
+        assert_eq!(file.size(), 2);
+
+        let file = File::from_blob(Blob::from("{}"), "data");
+        assert_eq!(file.ty(), "");
+    }
+
+    #[test]
+    fn last_modified_time() {
+        let file = File::from_blob(Blob::empty(), "a").with_last_modified(1_700_000_000_123);
+
+        assert_eq!(
+            file.last_modified_time(),
+            UNIX_EPOCH + Duration::from_millis(1_700_000_000_123)
+        );
+
+        // Sub-millisecond precision is truncated.
+        let time = UNIX_EPOCH + Duration::from_micros(1_700_000_000_123_999);
+        let file = file.with_last_modified_time(time);
+
+        assert_eq!(file.last_modified(), 1_700_000_000_123);
+
+        // Times before the epoch are negative.
+        let file = file.with_last_modified_time(UNIX_EPOCH - Duration::from_millis(1500));
+
+        assert_eq!(file.last_modified(), -1500);
+        assert_eq!(
+            file.last_modified_time(),
+            UNIX_EPOCH - Duration::from_millis(1500)
+        );
+    }
+
+    #[test]
+    fn last_modified_default() {
+        let file = File::from_blob(Blob::empty(), "empty");
+
+        assert_eq!(file.name(), "empty");
+        assert!(file.last_modified() > 0);
+        assert_eq!(Blob::from(file).size(), 0);
+    }
+}
@@ -0,0 +1,205 @@
+use std::ffi::OsStr;
+use std::fs;
+use std::io;
+use std::path::Path;
+#[cfg(feature = "mmap")]
+use std::sync::Arc;
+
+#[cfg(feature = "mmap")]
+use crate::part::Part;
+use crate::{guess_type, Blob, BlobOptions, File, LineEndings};
+
+/// Returns the options of a blob whose type is inferred from the extension
+/// of `path`. The type is empty if the extension is not recognized.
+///
+fn options_for(path: &Path) -> BlobOptions {
+    let ty = path
+        .file_name()
+        .and_then(OsStr::to_str)
+        .and_then(guess_type)
+        .map(Box::from);
+
+    BlobOptions::new(LineEndings::Transparent, ty)
+}
+
+impl Blob {
+    /// Reads the file at `path` into a new Blob that stores the bytes in parts
+    /// of at most 64KB.
+    ///
+    /// The type of the blob is inferred from the extension of `path`. See
+    /// `from_reader_chunked` for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or read.
+    ///
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref();
+        let file = fs::File::open(path)?;
+
+        Self::from_reader_chunked(file, Some(options_for(path)))
+    }
+
+    /// Maps the file at `path` into memory and returns a Blob that contains a
+    /// single part backed by the mapping.
+    ///
+    /// The bytes are never copied into the heap, so this is suitable for files
+    /// that are too large to read. The type of the blob is inferred from the
+    /// extension of `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or mapped.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while the returned blob, or
+    /// any blob sliced from it, is alive. Doing so changes the bytes of the
+    /// blob or causes a bus error when they are read.
+    ///
+    #[cfg(feature = "mmap")]
+    pub unsafe fn from_file_mmap<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref();
+        let file = fs::File::open(path)?;
+        let map = memmap2::Mmap::map(&file)?;
+
+        Ok(Self::from_data(
+            Arc::from([Part::from(map)]),
+            options_for(path),
+        ))
+    }
+}
+
+impl File {
+    /// Reads the file at `path` into a new File.
+    ///
+    /// The name of the file is the last component of `path`, and its type is
+    /// guessed from the extension of the name. The last modified timestamp is
+    /// read from the metadata of the file, or defaults to the current time if
+    /// the platform does not provide one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or read.
+    ///
+    pub fn from_path<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref();
+        let file = fs::File::open(path)?;
+        let modified = file.metadata()?.modified().ok();
+        let name = path.file_name().map(OsStr::to_string_lossy);
+
+        let blob = Blob::from_reader_chunked(file, Some(options_for(path)))?;
+        let file = Self::from_blob(blob, name.as_deref().unwrap_or_default());
+
+        Ok(match modified {
+            Some(time) => file.with_last_modified_time(time),
+            None => file,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crate::{Blob, File};
+
+    /// A file in the temporary directory that is removed when dropped.
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str, contents: &[u8]) -> Self {
+            let path = std::env::temp_dir().join(format!("blob-{}-{name}", std::process::id()));
+
+            fs::write(&path, contents).unwrap();
+            Self(path)
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    #[tokio::test]
+    async fn from_file() {
+        let file = TempFile::new("hello.TXT", b"Hello, world!");
+        let blob = Blob::from_file(&file.0).unwrap();
+
+        assert_eq!(blob.size(), 13);
+        assert_eq!(blob.ty(), "text/plain");
+        assert_eq!(blob.text().await.unwrap(), "Hello, world!");
+
+        // A file that is larger than a part is split into parts of 64KB.
+        let bytes: Vec<u8> = (0..150_000u32).map(|n| n as u8).collect();
+        let file = TempFile::new("large.bin", &bytes);
+        let blob = Blob::from_file(&file.0).unwrap();
+
+        assert_eq!(blob.ty(), "");
+        assert_eq!(blob.data.len(), 3);
+        assert_eq!(blob.to_vec(), bytes);
+        assert_eq!(
+            blob.slice(65_530, Some(65_540), None).to_vec(),
+            bytes[65_530..65_540]
+        );
+    }
+
+    #[test]
+    fn from_path() {
+        let temp = TempFile::new("bundle.tar.gz", b"\x1F\x8B\x08\x00");
+        let file = File::from_path(&temp.0).unwrap();
+        let modified = fs::metadata(&temp.0).unwrap().modified().unwrap();
+
+        assert!(file.name().ends_with("bundle.tar.gz"));
+        assert_eq!(file.ty(), "application/gzip");
+        assert_eq!(file.to_vec(), b"\x1F\x8B\x08\x00");
+        assert_eq!(
+            file.last_modified(),
+            File::from_blob(Blob::empty(), "")
+                .with_last_modified_time(modified)
+                .last_modified()
+        );
+
+        let error = File::from_path(std::env::temp_dir().join("blob-missing.json")).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn from_file_missing() {
+        let path = std::env::temp_dir().join("blob-does-not-exist.txt");
+        let error = Blob::from_file(path).unwrap_err();
+
+        assert_eq!(error.kind(), std::io::ErrorKind::NotFound);
+    }
+
+    #[cfg(feature = "mmap")]
+    #[tokio::test]
+    async fn from_file_mmap() {
+        use std::sync::Arc;
+
+        let file = TempFile::new("mapped.json", br#"{"message":"Hello, world!"}"#);
+        let blob = unsafe { Blob::from_file_mmap(&file.0) }.unwrap();
+        let heap = Blob::from_file(&file.0).unwrap();
+
+        assert_eq!(blob.size(), 27);
+        assert_eq!(blob.ty(), "application/json");
+        assert_eq!(blob.text().await, heap.text().await);
+        assert_eq!(blob, heap);
+
+        // Slices share the mapping instead of copying it.
+        let slice = blob.slice(12, Some(-2), None);
+
+        assert_eq!(slice.text().await.unwrap(), "Hello, world!");
+        assert_eq!(slice.size(), 13);
+        assert!(Arc::ptr_eq(&slice.data[0].bytes, &blob.data[0].bytes));
+
+        // An empty file maps to an empty blob.
+        let file = TempFile::new("empty.txt", b"");
+        let blob = unsafe { Blob::from_file_mmap(&file.0) }.unwrap();
+
+        assert_eq!(blob.size(), 0);
+        assert_eq!(blob.text().await.unwrap(), "");
+    }
+}
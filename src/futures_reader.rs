@@ -0,0 +1,199 @@
+use std::io::{self, BufRead};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_io::{AsyncBufRead, AsyncRead};
+
+use crate::reader::YIELD_BUDGET;
+use crate::{Blob, BlobReader};
+
+/// A reader that implements `futures::io::AsyncRead` and
+/// `futures::io::AsyncBufRead` over the bytes contained in a Blob.
+///
+/// This is the counterpart of `AsyncBlobReader` for executors outside of the
+/// tokio ecosystem. Reads complete immediately and the reader yields once for
+/// every 1MB that is read. The buffer returned by `poll_fill_buf` is the rest
+/// of the current part.
+///
+#[derive(Debug)]
+pub struct FuturesBlobReader<'a> {
+    inner: BlobReader<'a>,
+
+    /// The number of bytes that may be read before the next yield.
+    budget: usize,
+}
+
+impl<'a> FuturesBlobReader<'a> {
+    fn new(blob: &'a Blob) -> Self {
+        Self {
+            inner: blob.reader(),
+            budget: YIELD_BUDGET,
+        }
+    }
+
+    /// Returns true and schedules the task to be polled again if the budget
+    /// has been exhausted.
+    ///
+    fn should_yield(&mut self, cx: &mut Context<'_>) -> bool {
+        if self.budget > 0 {
+            return false;
+        }
+
+        self.budget = YIELD_BUDGET;
+        cx.waker().wake_by_ref();
+        true
+    }
+}
+
+impl AsyncRead for FuturesBlobReader<'_> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = &mut *self;
+
+        if this.should_yield(cx) {
+            return Poll::Pending;
+        }
+
+        // Fill as much of the buffer as possible, since each poll has a cost of
+        // its own.
+        let mut len = 0;
+
+        while len < buf.len() && this.budget > 0 {
+            let chunk = this.inner.fill_buf()?;
+
+            if chunk.is_empty() {
+                break;
+            }
+
+            let n = chunk.len().min(buf.len() - len).min(this.budget);
+
+            buf[len..len + n].copy_from_slice(&chunk[..n]);
+            this.inner.consume(n);
+            this.budget -= n;
+            len += n;
+        }
+
+        Poll::Ready(Ok(len))
+    }
+}
+
+impl AsyncBufRead for FuturesBlobReader<'_> {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        let this = self.get_mut();
+
+        if this.should_yield(cx) {
+            return Poll::Pending;
+        }
+
+        Poll::Ready(this.inner.fill_buf())
+    }
+
+    fn consume(mut self: Pin<&mut Self>, amt: usize) {
+        self.inner.consume(amt);
+        self.budget = self.budget.saturating_sub(amt);
+    }
+}
+
+impl Blob {
+    /// Returns a reader that implements `futures::io::AsyncRead` and
+    /// `futures::io::AsyncBufRead` over the bytes contained in self.
+    ///
+    /// This allows a blob to feed any consumer of the `futures` I/O traits
+    /// without coalescing the blob into a single buffer.
+    ///
+    pub fn futures_reader(&self) -> FuturesBlobReader<'_> {
+        FuturesBlobReader::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use futures::io::{AsyncBufReadExt, AsyncReadExt};
+
+    use crate::reader::YIELD_BUDGET;
+    use crate::Blob;
+
+    #[test]
+    fn read_chunks() {
+        let blob = Blob::new(vec![&b"ABC"[..], b"", b"DEFG", b"HIJKL"], None);
+
+        for slice in [blob.clone(), blob.slice(2, Some(-2), None)] {
+            let mut reader = slice.futures_reader();
+            let mut output = Vec::new();
+            let mut buf = [0; 3];
+
+            block_on(async {
+                loop {
+                    match reader.read(&mut buf).await.unwrap() {
+                        0 => break,
+                        n => output.extend_from_slice(&buf[..n]),
+                    }
+                }
+            });
+
+            assert_eq!(output, slice.to_vec());
+        }
+    }
+
+    #[test]
+    fn fill_buf() {
+        let blob = Blob::new(vec![&b"ABCD"[..], b"EFGH", b"IJKL"], None);
+        let slice = blob.slice(2, Some(-2), None);
+        let mut reader = slice.futures_reader();
+
+        block_on(async {
+            // The view starts and ends in the middle of a part.
+            assert_eq!(reader.fill_buf().await.unwrap(), b"CD");
+            reader.consume_unpin(2);
+            assert_eq!(reader.fill_buf().await.unwrap(), b"EFGH");
+            reader.consume_unpin(4);
+            assert_eq!(reader.fill_buf().await.unwrap(), b"IJ");
+            reader.consume_unpin(2);
+            assert_eq!(reader.fill_buf().await.unwrap(), b"");
+        });
+    }
+
+    #[test]
+    fn read_line() {
+        let blob = Blob::new(vec![&b"one\nt"[..], b"w", b"o\nthree"], None);
+        let mut reader = blob.futures_reader();
+        let mut line = String::new();
+
+        block_on(async {
+            reader.read_line(&mut line).await.unwrap();
+            assert_eq!(line, "one\n");
+
+            line.clear();
+            reader.read_line(&mut line).await.unwrap();
+            assert_eq!(line, "two\n");
+        });
+    }
+
+    #[test]
+    fn read_large() {
+        let part: Vec<u8> = (0..=255).cycle().take(1000).collect();
+        let blob = Blob::new(vec![part; 3000], None);
+        let mut output = Vec::new();
+
+        assert!(blob.size() > YIELD_BUDGET * 2);
+
+        block_on(blob.futures_reader().read_to_end(&mut output)).unwrap();
+        assert_eq!(output, blob.to_vec());
+    }
+
+    #[test]
+    fn copy() {
+        let blob = Blob::new(vec![&b"ABC"[..], b"", b"DEFG", b"HI"], None);
+        let slice = blob.slice(1, Some(-1), None);
+        let mut output = Vec::new();
+
+        let copied = block_on(futures::io::copy(slice.futures_reader(), &mut output)).unwrap();
+
+        assert_eq!(copied, 7);
+        assert_eq!(output, slice.to_vec());
+    }
+}
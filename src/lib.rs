@@ -1,10 +1,87 @@
-use js_sys::{ArrayBuffer, Uint8Array};
-use std::rc::Rc;
-use std::string::FromUtf8Error;
-
-#[derive(Debug)]
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::sync::Arc;
+
+#[cfg(feature = "tokio")]
+mod async_reader;
+#[cfg(feature = "js")]
+mod bindings;
+mod builder;
+mod checksum;
+#[cfg(feature = "compression")]
+mod compression;
+#[cfg(feature = "fastly")]
+mod compute;
+mod data_url;
+#[cfg(feature = "encoding")]
+mod decode;
+mod error;
+mod file;
+mod fs;
+#[cfg(feature = "futures-io")]
+mod futures_reader;
+#[cfg(feature = "js")]
+mod js;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "mime")]
+mod mime_type;
+#[cfg(feature = "reqwest")]
+mod multipart;
+mod newline;
+#[cfg(feature = "js")]
+mod object_url;
+mod part;
+mod reader;
+#[cfg(all(feature = "remote", not(target_arch = "wasm32")))]
+mod remote;
+#[cfg(feature = "axum")]
+mod response;
+#[cfg(feature = "serde")]
+mod serialize;
+mod sniff;
+mod source;
+#[cfg(feature = "stream")]
+mod stream;
+mod utf8;
+mod view;
+
+#[cfg(feature = "tokio")]
+pub use async_reader::AsyncBlobReader;
+#[cfg(feature = "js")]
+pub use bindings::JsBlob;
+pub use builder::{BlobBuilder, BlobOptionsBuilder};
+#[cfg(feature = "compression")]
+pub use compression::Encoding;
+pub use error::BlobError;
+pub use file::{guess_type, File};
+#[cfg(feature = "futures-io")]
+pub use futures_reader::FuturesBlobReader;
+#[cfg(feature = "json")]
+pub use json::BlobJsonError;
+pub use newline::{set_native_newline, Newline};
+#[cfg(feature = "js")]
+pub use object_url::{create_object_url, revoke_object_url, ObjectUrl};
+pub use part::BlobPart;
+pub use reader::BlobReader;
+pub use source::{BlobDataSource, LazyBlob, LazyBlobReader, ReadAtFuture};
+#[cfg(feature = "stream")]
+pub use stream::BlobChunks;
+pub use utf8::Utf8ErrorAt;
+pub use view::BlobView;
+
+use part::Part;
+
+#[derive(Clone)]
 pub struct Blob {
-    data: Rc<[Vec<u8>]>,
+    data: Arc<[Part]>,
+
+    /// The sum of the length of each part in data. This is computed once when
+    /// the data is constructed so that the size of a blob is available without
+    /// visiting each part.
+    len: usize,
+
     opts: BlobOptions,
     view: Option<(usize, usize)>,
 }
@@ -13,73 +90,268 @@ pub struct Blob {
 pub struct BlobOptions {
     endings: LineEndings,
     ty: Option<Box<str>>,
+
+    /// The Content-Type that was passed to `new` if it was discarded because
+    /// it is invalid. This is kept so that `Blob::try_new` can report it.
+    invalid_ty: Option<Box<str>>,
+
+    /// Strip a leading byte order mark when the blob is read as text.
+    strip_bom: bool,
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum LineEndings {
-    /// Convert newlines to the host system's native convention.
+    /// Convert newlines in string parts to the host system's native convention
+    /// when the blob is constructed. Binary parts are never converted.
+    ///
+    /// On wasm targets with the `js` feature, the convention is detected from
+    /// the platform that the JavaScript host reports at runtime. It can be
+    /// overridden with `set_native_newline`.
     Native,
 
     /// Copies newline characters into the blob without changing them.
+    #[default]
     Transparent,
+
+    /// Convert newlines in string parts to `\n` regardless of the host system,
+    /// as expected by POSIX text files.
+    Lf,
+
+    /// Convert newlines in string parts to `\r\n` regardless of the host
+    /// system, as required by protocols such as HTTP and SMTP.
+    Crlf,
+}
+
+impl LineEndings {
+    /// Returns the convention that string parts are converted to, or `None` if
+    /// they are stored as is.
+    ///
+    fn newline(self) -> Option<Newline> {
+        match self {
+            Self::Native => Some(newline::native()),
+            Self::Transparent => None,
+            Self::Lf => Some(Newline::Lf),
+            Self::Crlf => Some(Newline::Crlf),
+        }
+    }
+}
+
+/// A lazy, position-tracking reader over the parts of a blob that overlap an
+/// absolute byte range.
+///
+/// The cursor does not borrow the data it reads from. This allows it to be
+/// stored alongside an `Arc` of the underlying parts in contexts that require
+/// a 'static lifetime, such as the pull callback of a `ReadableStream`.
+///
+#[derive(Clone, Copy, Debug)]
+struct Cursor {
+    /// The index of the next part to visit.
+    index: usize,
+
+    /// The absolute index of the first byte of the next part to visit.
+    abs: usize,
+
+    /// The absolute start index of the range (inclusive).
+    from: usize,
+
+    /// The absolute end index of the range (exclusive).
+    to: usize,
+}
+
+impl Cursor {
+    #[inline]
+    fn new((from, to): (usize, usize)) -> Self {
+        Self {
+            index: 0,
+            abs: 0,
+            from,
+            to,
+        }
+    }
+
+    /// Returns true if there are no more bytes in range to read from `data`.
+    ///
+    #[inline]
+    fn is_done<P>(&self, data: &[P]) -> bool {
+        self.abs >= self.to || self.index >= data.len()
+    }
+
+    /// Advances the cursor past the next part that overlaps the range and
+    /// returns the index of that part along with the start and end offsets of
+    /// the overlap, relative to the start of the part.
+    ///
+    fn next_range<P>(&mut self, data: &[P]) -> Option<(usize, usize, usize)>
+    where
+        P: PartLen,
+    {
+        while !self.is_done(data) {
+            let index = self.index;
+            let edge = self.abs + data[index].part_len();
+
+            // Clamp the range to the bounds of the current part and convert
+            // the result to offsets relative to the start of the part.
+            let start = self.from.max(self.abs) - self.abs;
+            let end = self.to.min(edge).saturating_sub(self.abs);
+
+            self.index += 1;
+            self.abs = edge;
+
+            if start < end {
+                return Some((index, start, end));
+            }
+        }
+
+        None
+    }
+
+    /// Advances the cursor past the next part that overlaps the range and
+    /// returns the overlapping sub-slice of that part.
+    ///
+    #[inline]
+    fn next_chunk<'a, P>(&mut self, data: &'a [P]) -> Option<&'a [u8]>
+    where
+        P: PartLen + Deref<Target = [u8]>,
+    {
+        let (index, start, end) = self.next_range(data)?;
+        Some(&data[index][start..end])
+    }
+}
+
+/// The length of a part that a `Cursor` can visit.
+///
+/// Unlike the length of the dereferenced slice, this is available for the
+/// parts of a `LazyBlob` that have not been read yet.
+///
+trait PartLen {
+    fn part_len(&self) -> usize;
+}
+
+impl PartLen for Vec<u8> {
+    #[inline]
+    fn part_len(&self) -> usize {
+        self.len()
+    }
 }
 
-fn normalize_line_endings(input: &str) -> Option<String> {
+/// The byte order mark of a UTF-8 encoded text.
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Resolves the `start` and `end` arguments of `slice` to indices within the
+/// bounds of a blob of `size` bytes.
+///
+/// If `end` is `None`, `size` is used as the index of the last byte in range.
+/// An `end` that precedes `start` results in an empty range rather than an
+/// inverted one.
+///
+fn resolve_range(start: i64, end: Option<i64>, size: usize) -> (usize, usize) {
+    let start = relative_index(start, size);
+    let end = end.map_or(size, |end| relative_index(end, size)).max(start);
+
+    (start, end)
+}
+
+/// Resolves `index` to a position in `0..=size` using the same semantics as
+/// the `start` and `end` arguments of the JavaScript `Blob.prototype.slice`.
+///
+/// Negative values count backwards from `size` and are clamped to 0. Positive
+/// values are clamped to `size`.
+///
+fn relative_index(index: i64, size: usize) -> usize {
+    // The absolute value of index. If the conversion fails, we're on a target
+    // with a pointer width smaller than 64 bits and the value is guaranteed to
+    // be out of bounds.
+    let magnitude = usize::try_from(index.unsigned_abs()).unwrap_or(usize::MAX);
+
+    if index < 0 {
+        size.saturating_sub(magnitude)
+    } else {
+        magnitude.min(size)
+    }
+}
+
+/// Returns true if every char of `ty` is in the printable ASCII range.
+///
+#[inline]
+fn is_printable(ty: &str) -> bool {
+    ty.bytes().all(|byte| (0x20..=0x7e).contains(&byte))
+}
+
+/// Converts `ty` to lowercase in place if it is a valid Content-Type string.
+///
+/// Returns `None` if `ty` is empty or contains a char outside of the printable
+/// ASCII range.
+///
+fn normalize_type(mut ty: Box<str>) -> Option<Box<str>> {
+    if ty.is_empty() || !is_printable(&ty) {
+        return None;
+    }
+
+    ty.make_ascii_lowercase();
+    Some(ty)
+}
+
+/// Converts every line ending in `input` to the `newline` convention. A
+/// `\r\n` pair, a lone `\r`, and a lone `\n` are each treated as a single
+/// line break.
+///
+/// Returns `None` if `input` does not contain any line endings that need to be
+/// converted.
+///
+fn normalize_line_endings(input: &str, newline: Newline) -> Option<String> {
+    let target = newline.as_str();
+
     // The end offset of the last char that was read into the output buffer.
     let mut offset = 0;
 
-    // An iterator over the byte position of every occurance of `\n` in input.
-    #[cfg(target_os = "windows")]
-    let mut indices = input.match_indices("\n").peekable();
+    // The offset to resume searching for line endings from.
+    let mut search = 0;
 
-    // An iterator over the byte position of every occurance of `\r\n` in input.
-    #[cfg(not(target_os = "windows"))]
-    let mut indices = input.match_indices("\r\n").peekable();
-
-    // Conditionally preallocate an owned string to use as an output buffer.
-    let mut normalized = match indices.peek() {
-        // The input string requires line ending normalization. Preallocate a
-        // string with as much capacity as the input.
-        Some(_) => String::with_capacity(input.len()),
+    // An owned string to use as an output buffer. The buffer is allocated
+    // lazily when we find the first line ending that requires substitution.
+    let mut normalized: Option<String> = None;
 
-        // The input string does not require line ending normalization. Return
-        // early.
-        None => return None,
-    };
+    while let Some(index) = input[search..].find(['\r', '\n']) {
+        let start = search + index;
 
-    for (start, le) in &mut indices {
-        // A slice of the input string from the index of the last char that was
-        // read into the normalized output buffer to the start of the line
-        // ending that requires substitution.
-        let slice = &input[offset..start];
+        // A `\r` immediately followed by a `\n` is a single line ending. Take
+        // care not to treat it as two.
+        let end = if input[start..].starts_with("\r\n") {
+            start + 2
+        } else {
+            start + 1
+        };
 
-        // Append the slice to buffer from text. Starting from the end offset
-        // of the previous line ending that we replaced to the start of the
-        // line ending that we're currently replacing.
-        normalized.push_str(slice);
+        search = end;
 
-        // On windows, conditionally prepend an `\r` before any occurance of a
-        // `\n`. This maintains compatibility with protocols that use `\r` as
-        // a control char (such as SMTP).
-        #[cfg(target_os = "windows")]
-        if !slice.ends_with('\r') {
-            normalized.push('\r');
+        // The line ending already uses the target convention. Leave it in
+        // place so it is copied along with the next slice.
+        if &input[start..end] == target {
+            continue;
         }
 
-        // Unconditionally append the `\n` char.
-        normalized.push('\n');
+        // The input string requires line ending normalization. Preallocate a
+        // string with as much capacity as the input.
+        let buffer = normalized.get_or_insert_with(|| String::with_capacity(input.len()));
+
+        // Append the slice of the input from the end offset of the previous
+        // line ending that we replaced to the start of the line ending that
+        // we're currently replacing.
+        buffer.push_str(&input[offset..start]);
+        buffer.push_str(target);
 
         // Advance the offset pointer to the start index of the char that
         // immediately follows the line ending that was replaced.
-        offset = start + le.len();
+        offset = end;
     }
 
-    // Append the remaining slice of the input to the output buffer. The bounds
-    // check is necessary in cases where the input string ends with a line ending
-    // that needed replaced.
-    if let Some(remaining) = input.get(offset..) {
-        normalized.push_str(remaining);
-    }
+    // If the buffer was never allocated, the input string does not require
+    // line ending normalization.
+    let mut normalized = normalized?;
+
+    // Append the remaining slice of the input to the output buffer.
+    normalized.push_str(&input[offset..]);
 
     Some(normalized)
 }
@@ -93,246 +365,1619 @@ impl Blob {
         Vec<u8>: From<A>,
         I: IntoIterator<Item = A>,
     {
-        Self {
-            data: parts.into_iter().map(Vec::from).collect(),
-            opts: opts.unwrap_or_default(),
-            view: None,
+        let data = parts
+            .into_iter()
+            .map(|part| Vec::from(part).into())
+            .collect();
+
+        Self::from_data(data, opts.unwrap_or_default())
+    }
+
+    /// Constructs a new Blob instance from a sequence of heterogeneous parts,
+    /// like `from_parts`, but rejects an invalid Content-Type in `opts`
+    /// instead of discarding it.
+    ///
+    /// Only string parts are subject to the line ending conversion of `opts`,
+    /// so the returned Blob contains the same bytes as one returned by
+    /// `from_parts`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BlobError::InvalidType` if the Content-Type that `opts` was
+    /// constructed with contains a character outside of printable ASCII.
+    ///
+    pub fn try_new<I>(parts: I, opts: Option<BlobOptions>) -> Result<Self, BlobError>
+    where
+        I: IntoIterator,
+        I::Item: Into<BlobPart>,
+    {
+        if let Some(opts) = &opts {
+            opts.validate()?;
+        }
+
+        Ok(Self::from_parts(parts, opts))
+    }
+
+    /// Constructs a new Blob instance from a sequence of heterogeneous parts.
+    ///
+    /// String parts are subject to the line ending conversion specified by
+    /// `opts`. The bytes in view of a nested Blob are shared with the returned
+    /// Blob rather than copied.
+    ///
+    pub fn from_parts<I>(parts: I, opts: Option<BlobOptions>) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<BlobPart>,
+    {
+        let opts = opts.unwrap_or_default();
+        let newline = opts.endings.newline();
+        let mut data = Vec::new();
+
+        for part in parts {
+            match part.into() {
+                BlobPart::Bytes(bytes) => data.push(Part::from(bytes)),
+                BlobPart::Str(text) => {
+                    let text = match newline {
+                        Some(newline) => normalize_line_endings(&text, newline).unwrap_or(text),
+                        None => text,
+                    };
+
+                    data.push(Part::from(text.into_bytes()));
+                }
+                BlobPart::Blob(blob) => data.extend(blob.parts()),
+            }
         }
+
+        Self::from_data(data.into(), opts)
+    }
+
+    /// Constructs a new Blob instance that contains the bytes in view of each
+    /// blob in `blobs`, in order.
+    ///
+    /// The bytes are shared with `blobs` rather than copied. The returned Blob
+    /// has the same options as the first blob in `blobs`, including its
+    /// Content-Type. Use `slice` to give it a different Content-Type.
+    ///
+    pub fn concat(blobs: &[Blob]) -> Self {
+        let opts = blobs
+            .first()
+            .map(|blob| blob.opts.clone())
+            .unwrap_or_default();
+
+        let data = blobs.iter().flat_map(Blob::parts).collect::<Vec<_>>();
+
+        Self::from_data(data.into(), opts)
+    }
+
+    /// Returns a new Blob that contains the bytes in view of self followed by
+    /// `part`, with the same options as self.
+    ///
+    /// The bytes of self are shared with the returned Blob rather than copied.
+    /// Like any binary part, `part` is not subject to line ending conversion.
+    ///
+    pub fn with_appended(&self, part: impl Into<Vec<u8>>) -> Self {
+        let mut data = self.parts().collect::<Vec<_>>();
+
+        data.push(Part::from(part.into()));
+        Self::from_data(data.into(), self.opts.clone())
+    }
+
+    /// Returns a new Blob with the same options as self that stores the bytes
+    /// in view of self in a single part.
+    ///
+    /// Unlike `slice` and `concat`, the bytes are copied, so the returned Blob
+    /// shares nothing with self. This is useful for a blob that is built from
+    /// many small parts, since every accessor walks the parts in view. An empty
+    /// blob is compacted to a blob without any parts.
+    ///
+    pub fn compact(&self) -> Self {
+        let data: Arc<[Part]> = match self.coalesce() {
+            bytes if bytes.is_empty() => Arc::from([]),
+            bytes => Arc::from([Part::from(bytes)]),
+        };
+
+        Self::from_data(data, self.opts.clone())
+    }
+
+    /// Constructs a new Blob instance that does not contain any data.
+    ///
+    #[inline]
+    pub fn empty() -> Self {
+        Self::new(Vec::<Vec<u8>>::new(), None)
+    }
+
+    /// Returns a builder that can be used to construct a Blob from parts that
+    /// are pushed one at a time.
+    ///
+    #[inline]
+    pub fn builder() -> BlobBuilder {
+        BlobBuilder::new()
+    }
+
+    /// Constructs a new Blob instance from string parts.
+    ///
+    /// If `opts` specifies a line ending conversion other than
+    /// `LineEndings::Transparent`, the line endings of each part are converted
+    /// before they are stored. This keeps `size`, `bytes`, and `text`
+    /// consistent with each other.
+    ///
+    pub fn from_strings<I, S>(parts: I, opts: Option<BlobOptions>) -> Self
+    where
+        String: From<S>,
+        I: IntoIterator<Item = S>,
+    {
+        Self::from_parts(parts.into_iter().map(String::from), opts)
     }
 
     /// Create a new Blob instance from `start` to `end` and an optional
     /// Content-Type argument.
     ///
     /// If the `end` is `None`, the original length of the underlying buffer will
-    /// be used instead.
+    /// be used instead. Out of range indices are clamped to the size of self.
+    ///
+    /// Like the JavaScript Blob API, negative indices are resolved relative to
+    /// the end of self. For example, `blob.slice(-5, None, None)` returns a
+    /// Blob that contains the last five bytes of `blob`.
+    ///
+    /// The returned Blob uses the same line ending conversion as self.
+    ///
+    /// The `start` and `end` indices are relative to self. Slicing a blob that
+    /// is itself a slice addresses bytes within the parent's view rather than
+    /// the original data.
     ///
-    pub fn slice(&self, start: usize, end: Option<usize>, ty: Option<String>) -> Self {
+    pub fn slice(&self, start: i64, end: Option<i64>, ty: Option<String>) -> Self {
         // Store the optionally Content-Type string as a Box<str> to lower the
         // memory footprint of BlobOptions.
         let ty = ty.map(Box::from);
 
-        // If `end` is `None` use `self.size()` to get the index of the last byte
-        // that will be contained in the newly returned Blob.
-        let end = end.unwrap_or_else(|| self.size());
+        let (start, end) = resolve_range(start, end, self.size());
+
+        // The view of a slice is stored as absolute indices into self.data. If
+        // self is a slice, offset the relative indices by the start of the
+        // current view.
+        let (offset, _) = self.range();
 
         Self {
-            data: Rc::clone(&self.data),
-            opts: BlobOptions::new(LineEndings::Transparent, ty),
-            view: Some((start, end)),
+            data: Arc::clone(&self.data),
+            len: self.len,
+            opts: BlobOptions::new(self.opts.endings, ty).with_strip_bom(self.opts.strip_bom),
+            view: Some((offset + start, offset + end)),
         }
     }
 
+    /// Create a new Blob instance from `start` to `end` that has the same
+    /// Content-Type as self.
+    ///
+    /// This is a convenience for `slice` that saves callers from copying the
+    /// Content-Type of self by hand. Use `slice` with a `ty` of `None` for the
+    /// spec default of an empty Content-Type.
+    ///
+    pub fn slice_with_parent_type(&self, start: i64, end: Option<i64>) -> Self {
+        let mut slice = self.slice(start, end, None);
+
+        // The Content-Type of self has already been validated. Share it as is
+        // rather than parsing it again.
+        slice.opts.ty = self.opts.ty.clone();
+        slice
+    }
+
     /// The size of the underlying buffer in bytes.
     ///
     #[inline]
     pub fn size(&self) -> usize {
         match self.view {
-            // Get the length of the view by subtracting the end index from the
-            // start index. If overflow occurs, panic. In practice, we would want
-            // to branch on various deployment targets (i.e Node, Deno, Browsers)
-            // and throw a RangeError.
-            Some((from, to)) => to - from,
+            // Get the length of the view by subtracting the start index from the
+            // end index. The bounds of a view are clamped when it is created so
+            // `to` is never less than `from`. Saturate anyway rather than
+            // panicking if that invariant is ever broken.
+            Some((from, to)) => to.saturating_sub(from),
+
+            // The sum of each part is computed when self is constructed.
+            None => self.len,
+        }
+    }
 
-            // Get the length by calculating the sum of each part.
-            None => self.data.iter().map(|part| part.len()).sum(),
+    /// The size of the underlying buffer in bytes, or an error if the view of
+    /// self is inconsistent.
+    ///
+    /// Unlike `size`, which saturates, this reports a view whose end precedes
+    /// its start or extends past the end of the underlying data.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BlobError::RangeError` if the view of self does not lie within
+    /// the bytes of the underlying data.
+    ///
+    pub fn try_size(&self) -> Result<usize, BlobError> {
+        match self.view {
+            Some((from, to)) if from > to || to > self.len => Err(BlobError::RangeError {
+                start: from,
+                end: to,
+                size: self.len,
+            }),
+            _ => Ok(self.size()),
         }
     }
 
-    /// Returns an optional reference to the Content-Type string of the data
-    /// stored in self.
+    /// Returns the number of parts that store the bytes of self.
+    ///
+    /// This describes the underlying data rather than the view of self, so a
+    /// slice reports every part of its parent, including those that fall
+    /// outside of the slice.
     ///
     #[inline]
-    pub fn ty(&self) -> Option<&str> {
-        match &self.opts.ty {
-            Some(ty) => Some(ty),
-            None => None,
-        }
+    pub fn part_count(&self) -> usize {
+        self.data.len()
     }
 
-    /// An immmutable view of the underlying buffer.
+    /// Returns the length of each part that stores the bytes of self, in
+    /// order. Like `part_count`, the lengths are not clipped to the view of
+    /// self.
     ///
-    pub async fn array_buffer(&self) -> ArrayBuffer {
-        self.coalesce_js().buffer()
+    pub fn part_sizes(&self) -> Vec<usize> {
+        self.data.iter().map(PartLen::part_len).collect()
     }
 
-    /// Returns a `Future` that resolves to a byte slice.
+    /// Returns a reference to the Content-Type string of the data stored in
+    /// self.
     ///
-    pub async fn bytes(&self) -> Uint8Array {
-        self.coalesce_js()
+    /// Like the JavaScript Blob API, an empty string is returned if self does
+    /// not have a Content-Type.
+    ///
+    #[inline]
+    pub fn ty(&self) -> &str {
+        self.opts.ty.as_deref().unwrap_or_default()
     }
 
-    /// Returns a `ReadableStream` that can be used in a browser.
+    /// Returns the value of the `charset` parameter of the Content-Type of
+    /// self, if it has one.
     ///
-    pub async fn stream() {
-        todo!("integrate with web-sys and return an actual ReadableStream")
+    /// Surrounding whitespace and quotes are removed from the value. For
+    /// example, the charset of `text/plain; charset="utf-8"` is `utf-8`.
+    ///
+    pub fn charset(&self) -> Option<&str> {
+        // Skip the essence of the Content-Type and look at each parameter.
+        self.ty().split(';').skip(1).find_map(|param| {
+            let (name, value) = param.split_once('=')?;
+
+            if !name.trim().eq_ignore_ascii_case("charset") {
+                return None;
+            }
+
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+                .unwrap_or(value);
+
+            Some(value).filter(|value| !value.is_empty())
+        })
     }
 
-    /// Returns a `Future` that resolves to a &str.
+    /// Returns a `Future` that resolves to a copy of the bytes contained in
+    /// self.
     ///
-    /// # Errors
+    /// This is the native counterpart of the `Uint8Array` returned by `bytes`
+    /// when the `js` feature is enabled. It is a thin wrapper around
+    /// `bytes_sync`.
+    ///
+    #[cfg(not(feature = "js"))]
+    pub async fn bytes(&self) -> Vec<u8> {
+        self.bytes_sync()
+    }
+
+    /// Returns a copy of the bytes contained in self.
     ///
-    /// If the data stored in the Blob's buffer contains an invalid UTF-8 code
-    /// sequence.
+    /// The bytes are already in memory, so this returns immediately. This is
+    /// the native counterpart of the `Uint8Array` returned by `bytes_sync`
+    /// when the `js` feature is enabled.
     ///
-    pub async fn text(&self) -> Result<String, FromUtf8Error> {
-        // Validate that the bytes stored in self.data is valid UTF-8 sequence.
-        let text = String::from_utf8(self.coalesce())?;
+    #[cfg(not(feature = "js"))]
+    #[inline]
+    pub fn bytes_sync(&self) -> Vec<u8> {
+        self.coalesce()
+    }
 
-        if self.opts.endings == LineEndings::Native {
-            Ok(normalize_line_endings(&text).unwrap_or(text))
-        } else {
-            Ok(text)
-        }
+    /// Returns a copy of the bytes contained in self as an owned `Vec<u8>`.
+    ///
+    /// Unlike `bytes`, this does not require a JavaScript environment and can
+    /// be used on any target.
+    ///
+    #[inline]
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.coalesce()
     }
-}
 
-impl Blob {
-    fn coalesce(&self) -> Vec<u8> {
-        // Calculate the length of the buffer we are creating from self.
-        let capacity = self.size();
+    /// Consumes self and returns the bytes contained in self as an owned
+    /// `Vec<u8>`.
+    ///
+    /// If the bytes in view are stored in a single part whose buffer is not
+    /// shared with any other blob, the buffer is reused rather than copied.
+    /// Otherwise, this is equivalent to `to_vec`.
+    ///
+    pub fn into_vec(mut self) -> Vec<u8> {
+        let mut cursor = Cursor::new(self.range());
+        let first = cursor.next_range(&self.data);
+        let contiguous = cursor.next_range(&self.data).is_none();
 
-        // If we are working with a Blob slice, use the range stored at
-        // self.view. Otherwise, use 0 for the start index and `len` for the end.
-        let (from, to) = self.view.unwrap_or((0, capacity));
+        if let (Some((index, start, end)), true) = (first, contiguous) {
+            let bytes = Arc::get_mut(&mut self.data).and_then(|data| data[index].take(start, end));
 
-        // Allocate a zero-filled buffer with the total length
-        // of the view we are creating from self.
-        let mut buffer = vec![0; capacity];
+            if let Some(bytes) = bytes {
+                return bytes;
+            }
+        }
 
-        // The absolute index of the byte that will be read into the output
-        // buffer.
-        let mut abs = 0;
+        self.coalesce()
+    }
 
-        let mut ptr = 0;
+    /// Returns a `Future` that resolves to the bytes contained in self as a
+    /// `bytes::Bytes`.
+    ///
+    /// If the bytes in view are stored in a single part, the returned value
+    /// shares the buffer of that part rather than copying it. Otherwise, the
+    /// parts are coalesced into a new buffer.
+    ///
+    #[cfg(feature = "bytes")]
+    pub async fn bytes_native(&self) -> bytes::Bytes {
+        let mut cursor = Cursor::new(self.range());
+        let first = cursor.next_range(&self.data);
+        let contiguous = cursor.next_range(&self.data).is_none();
+
+        if let (Some((index, start, end)), true) = (first, contiguous) {
+            return self.data[index].slice(start, end).into_bytes();
+        }
 
-        // Iterate over each part of the blob.
-        for part in self.data.iter() {
-            let len = part.len();
-            let edge = abs + len;
+        self.coalesce().into()
+    }
 
-            // Determine if the start index is stored in part.
-            if from > edge {
-                abs = edge;
-                continue;
-            }
+    /// Returns a reference to the bytes contained in self if they are stored
+    /// contiguously in a single part.
+    ///
+    /// Returns `None` if the bytes are spread across more than one part. In
+    /// that case, callers can fall back to `to_vec`.
+    ///
+    pub fn as_contiguous(&self) -> Option<&[u8]> {
+        let mut chunks = self.chunks();
+
+        // An empty blob is trivially contiguous.
+        let first = chunks.next().unwrap_or_default();
+
+        match chunks.next() {
+            Some(_) => None,
+            None => Some(first),
+        }
+    }
+
+    /// Returns an iterator over the bytes contained in self.
+    ///
+    /// The iterator walks the parts of self that overlap the current view and
+    /// yields exactly `size()` bytes without allocating a buffer.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        self.chunks().flat_map(|chunk| chunk.iter().copied())
+    }
 
-            for byte in part.iter() {
-                abs += 1;
+    /// Returns true if self and `other` contain the same sequence of bytes,
+    /// regardless of their types.
+    ///
+    /// Unlike `==`, the Content-Type of each blob is ignored. The bytes are
+    /// compared one chunk at a time without coalescing either blob.
+    ///
+    pub fn bytes_eq(&self, other: &Self) -> bool {
+        if self.size() != other.size() {
+            return false;
+        }
 
-                if from >= abs {
-                    continue;
+        let mut lhs = self.chunks();
+        let mut rhs = other.chunks();
+
+        // The bytes of the current chunk of each blob that have not been
+        // compared yet.
+        let mut a: &[u8] = &[];
+        let mut b: &[u8] = &[];
+
+        // Walk the parts of both blobs in lockstep, comparing the longest run
+        // of bytes that is available in both of the current chunks. This
+        // avoids coalescing either blob.
+        loop {
+            if a.is_empty() {
+                match lhs.next() {
+                    Some(chunk) => a = chunk,
+                    None => break,
                 }
+            }
 
-                // If the offset pointer is greater than our end index, return.
-                if abs > to {
-                    return buffer;
+            if b.is_empty() {
+                match rhs.next() {
+                    Some(chunk) => b = chunk,
+                    None => break,
                 }
+            }
+
+            let len = a.len().min(b.len());
 
-                // Set the value at ptr to byte.
-                buffer[ptr] = *byte;
-                // Increment the offset pointer.
-                ptr += 1;
+            if a[..len] != b[..len] {
+                return false;
             }
+
+            a = &a[len..];
+            b = &b[len..];
         }
 
-        buffer
+        // One of the blobs ran out of bytes. They are only equal if the other
+        // did as well.
+        a.is_empty() && b.is_empty() && lhs.next().is_none() && rhs.next().is_none()
     }
 
-    fn coalesce_js(&self) -> Uint8Array {
-        // Calculate the length of the buffer we are creating from self.
-        let len = self.size();
+    /// Returns a reader that implements `std::io::Read` over the bytes
+    /// contained in self.
+    ///
+    /// This allows a blob to be piped into any `std::io` consumer, such as a
+    /// file, a hasher, or a compressor, without coalescing the blob into a
+    /// single buffer.
+    ///
+    pub fn reader(&self) -> BlobReader<'_> {
+        BlobReader::new(self)
+    }
 
-        // If we are working with a Blob slice, use the range stored at
-        // self.view. Otherwise, use 0 for the start index and `len` for the end.
-        let (from, to) = self.view.unwrap_or((0, len));
+    /// Returns a copy of the bytes contained in self, invoking `on_progress`
+    /// with `(bytes_read, total)` as each part is copied.
+    ///
+    /// This mirrors the `progress` events of the JavaScript `FileReader`. The
+    /// total is always `size()` and the last call reports `(total, total)`,
+    /// even if self is empty. Like `to_vec`, the bytes are already in memory,
+    /// so this returns immediately.
+    ///
+    pub fn read_with_progress<F>(&self, mut on_progress: F) -> Vec<u8>
+    where
+        F: FnMut(usize, usize),
+    {
+        let total = self.size();
+        let mut buffer = Vec::with_capacity(total);
 
-        // Allocate a zero-filled buffer with the total length
-        // of the view we are creating from self.
-        //
-        // TODO: determine what to do in the case of an overflow.
-        let buffer = Uint8Array::new_with_length(len as u32);
+        for chunk in self.chunks() {
+            buffer.extend_from_slice(chunk);
+            on_progress(buffer.len(), total);
+        }
 
-        // The absolute index of the byte that will be read into the output
-        // buffer.
-        let mut abs = 0;
+        // An empty blob does not have any chunks. Report completion anyway so
+        // callers can rely on receiving a final event.
+        if buffer.is_empty() {
+            on_progress(0, total);
+        }
 
-        let mut ptr = 0;
+        buffer
+    }
 
-        // Iterate over each part of the blob.
-        for part in self.data.iter() {
-            let edge = abs + part.len();
+    /// Returns a `Future` that resolves to a &str.
+    ///
+    /// When the `encoding` feature is enabled and the Content-Type of self has
+    /// a `charset` parameter that names an encoding other than UTF-8, the bytes
+    /// are decoded with that encoding instead, as if by `text_with_encoding`.
+    /// A missing or unrecognized charset falls back to UTF-8. To always decode
+    /// the bytes as UTF-8, use `String::from_utf8(blob.to_vec())`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BlobError::InvalidUtf8` if the data stored in the Blob's buffer
+    /// contains an invalid UTF-8 code sequence.
+    ///
+    pub async fn text(&self) -> Result<String, BlobError> {
+        #[cfg(feature = "encoding")]
+        if let Some(encoding) = self.text_encoding() {
+            return Ok(self.decode(encoding));
+        }
 
-            // Determine if the start index is stored in part.
-            if from > edge {
-                abs = edge;
-                continue;
+        // Decode each part into the output as it is validated, so the bytes
+        // are never coalesced into a second buffer. Line endings are converted
+        // when the blob is constructed, so the text can be returned as is.
+        if let Some(mut text) = self.decode_utf8() {
+            // A BOM that is split across parts decodes to a single U+FEFF.
+            if self.opts.strip_bom && text.starts_with('\u{FEFF}') {
+                text.drain(..'\u{FEFF}'.len_utf8());
             }
 
-            for byte in part.iter() {
-                abs += 1;
-
-                if from >= abs {
-                    continue;
-                }
+            return Ok(text);
+        }
 
-                // If the offset pointer is greater than our end index, return.
-                if abs > to {
-                    return buffer;
-                }
+        // The error owns the bytes of self, so coalesce them only when the
+        // bytes are invalid.
+        Ok(String::from_utf8(self.coalesce_text())?)
+    }
 
-                // Set the value at ptr to byte.
-                buffer.set_index(ptr, *byte);
+    /// Returns a `Future` that resolves to the text contained in self, with
+    /// each invalid UTF-8 sequence replaced with U+FFFD.
+    ///
+    /// Like the JavaScript Blob API, this never fails. The bytes are coalesced
+    /// before they are decoded, so a code point that is split across two parts
+    /// decodes normally. A code point that is cut by the view of a slice is
+    /// replaced.
+    ///
+    pub async fn text_lossy(&self) -> String {
+        match String::from_utf8(self.coalesce_text()) {
+            Ok(text) => text,
+            // Only pay for a second buffer if replacement is required.
+            Err(error) => String::from_utf8_lossy(error.as_bytes()).into_owned(),
+        }
+    }
 
-                // Increment the offset pointer.
-                ptr += 1;
-            }
+    /// Returns a `Future` that resolves to the lines of text contained in
+    /// self.
+    ///
+    /// Lines are separated by `\r\n`, `\n`, or a lone `\r`, the same line
+    /// breaks that are converted by `LineEndings`. The separators are not
+    /// included and a line break at the end of the text does not produce an
+    /// empty line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text of self can not be decoded. See `text`.
+    ///
+    pub async fn lines(&self) -> Result<Vec<String>, BlobError> {
+        let text = self.text().await?;
+        let mut lines = Vec::new();
+        let mut rest = text.as_str();
+
+        while !rest.is_empty() {
+            let end = rest.find(['\r', '\n']).unwrap_or(rest.len());
+
+            lines.push(rest[..end].to_owned());
+            rest = &rest[end..];
+            rest = rest
+                .strip_prefix("\r\n")
+                .or_else(|| rest.strip_prefix(['\r', '\n']))
+                .unwrap_or(rest);
         }
 
-        buffer
+        Ok(lines)
     }
 }
 
-impl BlobOptions {
+impl Blob {
+    /// Constructs a new Blob instance that contains every byte in `data`.
+    ///
     #[inline]
-    pub fn new(endings: LineEndings, ty: Option<Box<str>>) -> Self {
-        Self { endings, ty }
+    fn from_data(data: Arc<[Part]>, opts: BlobOptions) -> Self {
+        Self {
+            len: data.iter().map(PartLen::part_len).sum(),
+            data,
+            opts,
+            view: None,
+        }
     }
-}
 
-impl Default for BlobOptions {
+    /// The absolute range of bytes in self.data that are contained in self.
+    ///
     #[inline]
-    fn default() -> Self {
-        Self::new(LineEndings::Transparent, None)
+    fn range(&self) -> (usize, usize) {
+        self.view.unwrap_or((0, self.len))
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::{Blob, BlobOptions, LineEndings};
 
-    const DATA: &[u8] = b"First line\r\nSecond line\nThird line\r\nFourth line";
+    /// Returns an iterator over the sub-slice of each part that overlaps the
+    /// view of self. Parts that fall entirely outside of the view are skipped.
+    ///
+    fn chunks(&self) -> impl Iterator<Item = &[u8]> {
+        let mut cursor = Cursor::new(self.range());
+        std::iter::from_fn(move || cursor.next_chunk(&self.data))
+    }
 
-    //
-    // TODO: figure out how to test with js-sys and setup wasm-bindgen.
-    //
-    // #[tokio::test]
-    // async fn bytes() {
-    //     let blob = Blob::new(vec![DATA.to_vec()], None);
-    //     let bytes = blob.bytes().await;
+    /// Returns an iterator over the parts of self that overlap the view of
+    /// self, clipped to the bounds of the view. The returned parts share their
+    /// buffers with self.
+    ///
+    fn parts(&self) -> impl Iterator<Item = Part> + '_ {
+        let mut cursor = Cursor::new(self.range());
 
-    //     for (index, byte) in DATA.iter().enumerate() {
-    //         assert_eq!(bytes.get_index(index as u32), *byte);
-    //     }
-    // }
+        std::iter::from_fn(move || {
+            let (index, start, end) = cursor.next_range(&self.data)?;
+            Some(self.data[index].slice(start, end))
+        })
+    }
 
-    #[tokio::test]
-    async fn multipart() {
-        let blob = Blob::new(vec![DATA, DATA], None);
-        let mut data = DATA.to_vec();
+    /// Coalesces the bytes of self for decoding as UTF-8, removing a leading
+    /// BOM if self is configured to strip it.
+    ///
+    fn coalesce_text(&self) -> Vec<u8> {
+        let mut bytes = self.coalesce();
 
-        data.extend_from_slice(DATA);
+        // The BOM is checked after coalescing so that a BOM that is split
+        // across the first parts of self is still detected.
+        if self.opts.strip_bom && bytes.starts_with(UTF8_BOM) {
+            bytes.drain(..UTF8_BOM.len());
+        }
 
-        assert_eq!(blob.text().await.unwrap().as_bytes(), data);
+        bytes
+    }
+
+    fn coalesce(&self) -> Vec<u8> {
+        // Preallocate a buffer with the total length of the view we are
+        // creating from self. If the view extends past the end of the data,
+        // the buffer simply ends up shorter than its capacity.
+        let mut buffer = Vec::with_capacity(self.size());
+
+        // Copy the overlapping range of each part with a single memcpy rather
+        // than visiting every byte.
+        for chunk in self.chunks() {
+            buffer.extend_from_slice(chunk);
+        }
+
+        buffer
+    }
+}
+
+impl Default for Blob {
+    #[inline]
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl From<&str> for Blob {
+    #[inline]
+    fn from(text: &str) -> Self {
+        Self::from(text.as_bytes())
+    }
+}
+
+impl From<String> for Blob {
+    #[inline]
+    fn from(text: String) -> Self {
+        Self::from(text.into_bytes())
+    }
+}
+
+impl From<&[u8]> for Blob {
+    #[inline]
+    fn from(bytes: &[u8]) -> Self {
+        Self::new([bytes], None)
+    }
+}
+
+impl From<Vec<u8>> for Blob {
+    #[inline]
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::new([bytes], None)
+    }
+}
+
+impl From<(String, &str)> for Blob {
+    /// Constructs a single part Blob from a string and a Content-Type.
+    ///
+    #[inline]
+    fn from((text, ty): (String, &str)) -> Self {
+        let opts = BlobOptions::new(LineEndings::Transparent, Some(ty.into()));
+        Self::new([text.into_bytes()], Some(opts))
+    }
+}
+
+impl Debug for Blob {
+    /// Summarizes the parts of self instead of printing their contents.
+    ///
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Blob")
+            .field("size", &self.size())
+            .field("type", &self.ty())
+            .field("parts", &self.data.len())
+            .field("view", &self.view)
+            .finish()
+    }
+}
+
+impl Display for Blob {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Blob {{ size: {}, type: {:?} }}", self.size(), self.ty())
+    }
+}
+
+impl PartialEq for Blob {
+    /// Two blobs are equal if they have the same Content-Type and contain the
+    /// same sequence of bytes, regardless of how those bytes are split into
+    /// parts or whether either blob is a slice.
+    ///
+    fn eq(&self, other: &Self) -> bool {
+        self.ty() == other.ty() && self.bytes_eq(other)
+    }
+}
+
+impl Eq for Blob {}
+
+impl Hash for Blob {
+    /// Hashes the Content-Type and the sequence of bytes contained in self.
+    ///
+    /// The result is consistent with `PartialEq`. Blobs that contain the same
+    /// bytes produce the same hash regardless of how those bytes are split
+    /// into parts.
+    ///
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Not every Hasher produces the same output for a sequence of writes as
+        // it does for a single write of the concatenated bytes. Feed the bytes
+        // to the hasher in fixed size blocks so the boundaries of each part do
+        // not influence the result.
+        let mut block = [0; 256];
+        let mut len = 0;
+
+        self.ty().hash(state);
+        state.write_usize(self.size());
+
+        for mut chunk in self.chunks() {
+            while !chunk.is_empty() {
+                let n = chunk.len().min(block.len() - len);
+
+                block[len..len + n].copy_from_slice(&chunk[..n]);
+                chunk = &chunk[n..];
+                len += n;
+
+                if len == block.len() {
+                    state.write(&block);
+                    len = 0;
+                }
+            }
+        }
+
+        state.write(&block[..len]);
+    }
+}
+
+impl BlobOptions {
+    /// Returns a builder that can be used to construct BlobOptions without
+    /// boxing the Content-Type by hand.
+    ///
+    #[inline]
+    pub fn builder() -> BlobOptionsBuilder {
+        BlobOptionsBuilder::new()
+    }
+
+    /// Constructs a new BlobOptions instance.
+    ///
+    /// Per the Blob spec, `ty` is converted to lowercase. If it contains any
+    /// characters outside of the printable ASCII range (U+0020 to U+007E), it
+    /// is discarded and the blob will not have a Content-Type. `Blob::try_new`
+    /// reports a discarded Content-Type as an error.
+    ///
+    #[inline]
+    pub fn new(endings: LineEndings, ty: Option<Box<str>>) -> Self {
+        let (ty, invalid_ty) = match ty {
+            Some(ty) if !is_printable(&ty) => (None, Some(ty)),
+            ty => (ty.and_then(normalize_type), None),
+        };
+
+        Self {
+            endings,
+            ty,
+            invalid_ty,
+            strip_bom: false,
+        }
+    }
+
+    /// Returns new options like `new`, but rejects an invalid Content-Type
+    /// instead of replacing it with the empty string.
+    ///
+    /// An empty type is valid and means that the blob does not have a type.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BlobError::InvalidType` if `ty` contains a character outside of
+    /// printable ASCII.
+    ///
+    pub fn try_new(endings: LineEndings, ty: Option<Box<str>>) -> Result<Self, BlobError> {
+        let opts = Self::new(endings, ty);
+
+        opts.validate()?;
+        Ok(opts)
+    }
+
+    /// Sets whether a leading byte order mark is stripped when the blob is
+    /// read as text.
+    ///
+    /// A UTF-8 BOM is removed by `text` and `text_lossy`. When the `encoding`
+    /// feature is enabled, a UTF-16 BOM is also detected and the text is
+    /// decoded accordingly. A BOM overrides both the charset parameter of the
+    /// Content-Type and the label passed to `text_with_encoding`, the same way
+    /// the WHATWG "decode" algorithm sniffs a BOM.
+    ///
+    #[inline]
+    pub fn with_strip_bom(mut self, strip_bom: bool) -> Self {
+        self.strip_bom = strip_bom;
+        self
+    }
+
+    /// Returns an error if the Content-Type that self was constructed with was
+    /// discarded because it is invalid.
+    ///
+    fn validate(&self) -> Result<(), BlobError> {
+        match &self.invalid_ty {
+            Some(ty) => Err(BlobError::InvalidType(ty.clone())),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Default for BlobOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new(LineEndings::Transparent, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{HashMap, HashSet};
+    use std::hash::{Hash, Hasher};
+    use std::sync::Arc;
+
+    use super::{
+        normalize_line_endings, Blob, BlobError, BlobOptions, BlobPart, Cursor, LineEndings,
+        Newline,
+    };
+    use crate::part::Buffer;
+
+    const TEXT: &str = "First line\r\nSecond line\nThird line\r\nFourth line";
+    const DATA: &[u8] = TEXT.as_bytes();
+
+    #[cfg(not(feature = "js"))]
+    #[tokio::test]
+    async fn bytes() {
+        let blob = Blob::new(vec![DATA.to_vec()], None);
+        let bytes = blob.bytes().await;
+
+        assert_eq!(bytes.len(), DATA.len());
+
+        for (index, byte) in DATA.iter().enumerate() {
+            assert_eq!(bytes[index], *byte);
+        }
+    }
+
+    #[cfg(not(feature = "js"))]
+    #[test]
+    fn bytes_sync() {
+        let blob = Blob::new(vec![&b"ABC"[..], b"DEFG"], None);
+
+        assert_eq!(blob.bytes_sync(), b"ABCDEFG");
+        assert_eq!(blob.slice(2, Some(-2), None).bytes_sync(), b"CDE");
+        assert_eq!(Blob::empty().bytes_sync(), b"");
+    }
+
+    #[tokio::test]
+    async fn empty() {
+        let blob = Blob::empty();
+
+        assert_eq!(blob.size(), 0);
+        assert_eq!(blob.ty(), "");
+        assert_eq!(blob.text().await.unwrap(), "");
+
+        // Coalescing an empty blob does not allocate.
+        let bytes = blob.coalesce();
+
+        assert!(bytes.is_empty());
+        assert_eq!(bytes.capacity(), 0);
+
+        let slice = blob.slice(0, Some(0), None);
+
+        assert_eq!(slice.size(), 0);
+        assert_eq!(slice.text().await.unwrap(), "");
+        assert_eq!(slice.coalesce().capacity(), 0);
+
+        // Out of range and negative indices are clamped to 0.
+        assert_eq!(blob.slice(5, Some(10), None).size(), 0);
+        assert_eq!(blob.slice(-5, None, None).size(), 0);
+
+        // A blob made of empty parts behaves the same way.
+        let blob = Blob::new(vec![&b""[..], b""], None);
+
+        assert_eq!(blob.size(), 0);
+        assert_eq!(blob.text().await.unwrap(), "");
+        assert_eq!(blob.coalesce().capacity(), 0);
+    }
+
+    #[tokio::test]
+    async fn default() {
+        let blob = Blob::default();
+
+        assert_eq!(blob, Blob::empty());
+        assert_eq!(blob.size(), 0);
+        assert_eq!(blob.text().await.unwrap(), "");
+
+        let slice = blob.slice(0, None, None);
+
+        assert_eq!(slice.size(), 0);
+        assert_eq!(slice.to_vec(), b"");
+    }
+
+    #[test]
+    fn to_vec_binary() {
+        let blob = Blob::new(
+            vec![&[0xff, 0x00, 0xfe][..], &[0x80, 0xc3], &[0x28, 0x00]],
+            None,
+        );
+
+        assert!(std::str::from_utf8(&blob.to_vec()).is_err());
+        assert_eq!(blob.to_vec(), [0xff, 0x00, 0xfe, 0x80, 0xc3, 0x28, 0x00]);
+
+        // A slice that spans the boundaries of every part.
+        assert_eq!(
+            blob.slice(2, Some(6), None).to_vec(),
+            [0xfe, 0x80, 0xc3, 0x28]
+        );
+
+        // A slice of a slice.
+        let slice = blob.slice(1, None, None).slice(3, Some(-1), None);
+        assert_eq!(slice.to_vec(), [0xc3, 0x28]);
+    }
+
+    #[test]
+    fn into_vec() {
+        // A single part that is not shared reuses the buffer.
+        let bytes = DATA.to_vec();
+        let ptr = bytes.as_ptr();
+        let vec = Blob::from(bytes).into_vec();
+
+        assert_eq!(vec, DATA);
+        assert_eq!(vec.as_ptr(), ptr);
+
+        // A slice of a single part keeps the allocation of the buffer once the
+        // blob it was sliced from is dropped.
+        let bytes = DATA.to_vec();
+        let ptr = bytes.as_ptr();
+        let slice = Blob::from(bytes).slice(12, Some(23), None);
+        let vec = slice.into_vec();
+
+        assert_eq!(vec, b"Second line");
+        assert_eq!(vec.as_ptr(), ptr);
+
+        // A buffer that is shared with another blob is copied and left intact.
+        let blob = Blob::from(DATA);
+        let clone = blob.clone();
+        let vec = clone.into_vec();
+
+        assert_eq!(vec, DATA);
+        assert_ne!(vec.as_ptr(), blob.data[0].as_ptr());
+        assert_eq!(blob.to_vec(), DATA);
+
+        let nested = Blob::from_parts([&blob], None);
+        assert_eq!(nested.into_vec(), DATA);
+        assert_eq!(blob.to_vec(), DATA);
+
+        // Bytes that span multiple parts are coalesced.
+        let blob = Blob::new(vec![DATA, DATA], None);
+        assert_eq!(
+            blob.slice(40, Some(50), None).into_vec(),
+            [&DATA[40..], &DATA[..3]].concat()
+        );
+        assert!(Blob::empty().into_vec().is_empty());
+    }
+
+    #[cfg(feature = "bytes")]
+    #[tokio::test]
+    async fn bytes_native() {
+        // A single part shares its buffer.
+        let blob = Blob::from(DATA);
+        let bytes = blob.bytes_native().await;
+
+        assert_eq!(bytes, DATA);
+        assert_eq!(bytes.as_ptr(), blob.data[0].as_ptr());
+
+        // A slice within a single part shares the buffer at an offset.
+        let slice = blob.slice(12, Some(23), None);
+        let bytes = slice.bytes_native().await;
+
+        assert_eq!(bytes, &b"Second line"[..]);
+        assert_eq!(bytes.as_ptr(), blob.data[0][12..].as_ptr());
+
+        // Bytes that span multiple parts are coalesced.
+        let blob = Blob::new(vec![DATA, DATA], None);
+        let bytes = blob.slice(40, Some(50), None).bytes_native().await;
+
+        assert_eq!(bytes, [&DATA[40..], &DATA[..3]].concat());
+        assert!(Blob::empty().bytes_native().await.is_empty());
+    }
+
+    #[test]
+    fn as_contiguous() {
+        let blob = Blob::new(vec![DATA], None);
+
+        // A single part blob without a view is borrowed directly.
+        assert_eq!(blob.as_contiguous(), Some(DATA));
+        assert!(std::ptr::eq(
+            blob.as_contiguous().unwrap(),
+            &blob.data[0][..]
+        ));
+
+        let blob = Blob::new(vec![&b"ABC"[..], b"DEF"], None);
+
+        // The bytes are spread across two parts.
+        assert_eq!(blob.as_contiguous(), None);
+        assert_eq!(blob.slice(2, Some(4), None).as_contiguous(), None);
+
+        // The view lies entirely within a single part.
+        assert_eq!(
+            blob.slice(0, Some(3), None).as_contiguous(),
+            Some(&b"ABC"[..])
+        );
+        assert_eq!(blob.slice(4, None, None).as_contiguous(), Some(&b"EF"[..]));
+
+        // Empty blobs and views are contiguous.
+        assert_eq!(Blob::empty().as_contiguous(), Some(&[][..]));
+        assert_eq!(blob.slice(3, Some(3), None).as_contiguous(), Some(&[][..]));
+    }
+
+    #[tokio::test]
+    async fn clone() {
+        let blob = Blob::new(vec![DATA, DATA], None);
+        let clone = blob.clone();
+
+        // The clone shares the underlying allocation with the original.
+        assert!(Arc::ptr_eq(&blob.data, &clone.data));
+        assert_eq!(Arc::strong_count(&blob.data), 2);
+        assert_eq!(clone.text().await.unwrap(), blob.text().await.unwrap());
+
+        // Clones of a slice preserve the view.
+        let slice = blob.slice(12, Some(23), None);
+        let clone = slice.clone();
+
+        assert_eq!(Arc::strong_count(&blob.data), 4);
+        assert_eq!(clone.view, slice.view);
+        assert_eq!(clone.text().await.unwrap(), "Second line");
+
+        drop(clone);
+        assert_eq!(Arc::strong_count(&blob.data), 3);
+    }
+
+    #[test]
+    fn eq() {
+        let multipart = Blob::new(vec![&b"Second"[..], b" ", b"line"], None);
+        let single = Blob::new(vec![&b"Second line"[..]], None);
+
+        assert_eq!(multipart, single);
+
+        // A slice equals a freshly constructed blob with the same range.
+        let slice = Blob::new(vec![DATA, DATA], None).slice(12, Some(23), None);
+
+        assert_eq!(slice, single);
+        assert_eq!(slice, multipart);
+
+        // Different bytes or a different Content-Type are not equal.
+        assert_ne!(slice, Blob::new(vec![&b"Second lime"[..]], None));
+        assert_ne!(slice, Blob::new(vec![&b"Second"[..]], None));
+        assert_ne!(
+            slice,
+            Blob::new(
+                vec![&b"Second line"[..]],
+                Some(BlobOptions::new(
+                    LineEndings::Transparent,
+                    Some("text/plain".into())
+                ))
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn clone_options() {
+        let opts = BlobOptions::new(LineEndings::Native, Some("text/plain".into()));
+        let blob = Blob::from_strings(vec![TEXT], Some(opts));
+        let clone = blob.clone();
+
+        assert_eq!(clone.ty(), "text/plain");
+        assert_eq!(clone.opts.endings, LineEndings::Native);
+        assert_eq!(clone.text().await.unwrap(), blob.text().await.unwrap());
+        assert_eq!(Arc::strong_count(&clone.data), 2);
+    }
+
+    #[test]
+    fn eq_lockstep() {
+        let single = Blob::new(vec![&b"ABCDEF"[..]], None);
+        let three = Blob::new(vec![&b"AB"[..], b"CDE", b"F"], None);
+        let misaligned = Blob::new(vec![&b"A"[..], b"", b"BCD", b"EF"], None);
+
+        assert_eq!(single, three);
+        assert_eq!(three, misaligned);
+        assert_eq!(misaligned, single);
+
+        // A difference in the last byte of a chunk.
+        assert_ne!(three, Blob::new(vec![&b"A"[..], b"BCDEG"], None));
+
+        // Unequal lengths that share a common prefix.
+        assert_ne!(three, Blob::new(vec![&b"ABC"[..], b"DE"], None));
+        assert_ne!(three, Blob::new(vec![&b"ABC"[..], b"DEFG"], None));
+
+        // Empty blobs are equal to each other.
+        assert_eq!(Blob::empty(), Blob::new(vec![&b""[..]], None));
+        assert_eq!(Blob::empty(), three.slice(2, Some(2), None));
+    }
+
+    #[tokio::test]
+    async fn lines() {
+        let blob = Blob::new(vec![&DATA[..15], &DATA[15..]], None);
+
+        assert_eq!(
+            blob.lines().await.unwrap(),
+            ["First line", "Second line", "Third line", "Fourth line"]
+        );
+
+        // A lone carriage return is a line break, a final line break does not
+        // produce an empty line, but empty lines in between are kept.
+        let blob = Blob::from("a\rb\n\nc\r\n");
+        assert_eq!(blob.lines().await.unwrap(), ["a", "b", "", "c"]);
+
+        assert_eq!(Blob::from("\r\n").lines().await.unwrap(), [""]);
+        assert!(Blob::empty().lines().await.unwrap().is_empty());
+
+        let invalid = Blob::new(vec![&b"ok\n\xFF"[..]], None);
+        assert!(matches!(
+            invalid.lines().await,
+            Err(BlobError::InvalidUtf8(_))
+        ));
+    }
+
+    #[test]
+    fn bytes_eq() {
+        let text = BlobOptions::new(LineEndings::Transparent, Some("text/plain".into()));
+        let json = BlobOptions::new(LineEndings::Transparent, Some("application/json".into()));
+
+        let lhs = Blob::new(vec![&b"{\"a\""[..], b":1}"], Some(text));
+        let rhs = Blob::new(vec![&b"{"[..], b"\"a\":1}"], Some(json));
+
+        // The same bytes with different types.
+        assert!(lhs.bytes_eq(&rhs));
+        assert_ne!(lhs, rhs);
+
+        // The type of a slice does not matter either.
+        assert!(lhs.slice(1, Some(4), None).bytes_eq(&Blob::from("\"a\"")));
+
+        // Different bytes, or a different size, are never equal.
+        assert!(!lhs.bytes_eq(&Blob::from("{\"a\":2}")));
+        assert!(!lhs.bytes_eq(&lhs.slice(0, Some(-1), None)));
+        assert!(Blob::empty().bytes_eq(&lhs.slice(3, Some(3), None)));
+    }
+
+    #[test]
+    fn ty_normalized() {
+        let opts = BlobOptions::new(LineEndings::Transparent, Some("TEXT/Plain".into()));
+        assert_eq!(Blob::new(vec![DATA], Some(opts)).ty(), "text/plain");
+
+        let opts = BlobOptions::new(LineEndings::Transparent, Some("text/\u{1F600}".into()));
+        assert_eq!(Blob::new(vec![DATA], Some(opts)).ty(), "");
+
+        assert_eq!(Blob::new(vec![DATA], None).ty(), "");
+
+        // The type of a slice is validated the same way.
+        let blob = Blob::new(vec![DATA], None);
+
+        assert_eq!(
+            blob.slice(0, None, Some("Image/PNG".into())).ty(),
+            "image/png"
+        );
+        assert_eq!(blob.slice(0, None, Some("caf\u{e9}".into())).ty(), "");
+    }
+
+    #[tokio::test]
+    async fn try_new() {
+        let opts = BlobOptions::new(LineEndings::Crlf, Some("text/plain".into()));
+        let parts = || {
+            vec![
+                BlobPart::from("one\ntwo"),
+                BlobPart::from(&b"\r\xFF\n"[..]),
+                BlobPart::from(Blob::from("three\n")),
+            ]
+        };
+
+        let blob = Blob::try_new(parts(), Some(opts.clone())).unwrap();
+
+        // Only string parts are converted, so binary parts do not need to be
+        // valid UTF-8 and the bytes match those of from_parts.
+        assert_eq!(blob.to_vec(), b"one\r\ntwo\r\xFF\nthree\n");
+        assert_eq!(blob, Blob::from_parts(parts(), Some(opts)));
+        assert_eq!(blob.ty(), "text/plain");
+
+        let blob = Blob::try_new(vec![&b"\xFF\n"[..]], None).unwrap();
+        assert_eq!(blob.to_vec(), b"\xFF\n");
+    }
+
+    #[test]
+    fn try_new_invalid() {
+        // An invalid Content-Type is rejected even if the options were
+        // constructed with BlobOptions::new, which discards it.
+        let opts = BlobOptions::new(LineEndings::Native, Some("bad\u{1}type".into()));
+
+        assert_eq!(opts.ty, None);
+        assert_eq!(
+            Blob::try_new(vec!["ok"], Some(opts)).unwrap_err(),
+            BlobError::InvalidType("bad\u{1}type".into())
+        );
+
+        let opts = BlobOptions::builder().ty("caf\u{e9}").build();
+        assert_eq!(
+            Blob::try_new(vec!["ok"], Some(opts)).unwrap_err(),
+            BlobError::InvalidType("caf\u{e9}".into())
+        );
+
+        // A Content-Type with a control character is rejected rather than
+        // cleared.
+        let ty = "text/plain\r\nX-Injected: 1";
+
+        assert_eq!(
+            BlobOptions::try_new(LineEndings::Transparent, Some(ty.into())).unwrap_err(),
+            BlobError::InvalidType(ty.into())
+        );
+        assert_eq!(
+            BlobError::InvalidType("caf\u{e9}".into()).to_string(),
+            "invalid content type: \"caf\u{e9}\""
+        );
+
+        // Valid and empty types are accepted and normalized.
+        let opts = BlobOptions::try_new(LineEndings::Transparent, Some("Text/CSV".into())).unwrap();
+        assert_eq!(
+            Blob::try_new(vec![DATA], Some(opts)).unwrap().ty(),
+            "text/csv"
+        );
+
+        let opts = BlobOptions::try_new(LineEndings::Transparent, Some("".into())).unwrap();
+        assert_eq!(Blob::try_new(vec![DATA], Some(opts)).unwrap().ty(), "");
+    }
+
+    #[test]
+    fn ty_control_characters() {
+        let blob = Blob::new(vec![DATA], None);
+
+        // Any character outside of printable ASCII clears the type, including
+        // NUL, other C0 controls, and DEL.
+        for ty in [
+            "Text/PLAIN\u{0}",
+            "text/plain\r\nX-Injected: 1",
+            "text/\u{7f}",
+        ] {
+            let opts = BlobOptions::new(LineEndings::Transparent, Some(ty.into()));
+
+            assert_eq!(Blob::new(vec![DATA], Some(opts)).ty(), "", "{ty:?}");
+            assert_eq!(blob.slice(0, None, Some(ty.into())).ty(), "", "{ty:?}");
+            let opts = BlobOptions::builder().ty(ty).build();
+            assert_eq!(Blob::new(vec![DATA], Some(opts)).ty(), "", "{ty:?}");
+        }
+
+        // Printable ASCII, including spaces and parameters, is kept and
+        // lowercased.
+        let ty = Some("Text/PLAIN; Charset=UTF-8".into());
+        assert_eq!(blob.slice(0, None, ty).ty(), "text/plain; charset=utf-8");
+    }
+
+    #[test]
+    fn charset() {
+        let charset = |ty: &str| {
+            let opts = BlobOptions::new(LineEndings::Transparent, Some(ty.into()));
+            Blob::new(vec![DATA], Some(opts))
+                .charset()
+                .map(str::to_owned)
+        };
+
+        assert_eq!(
+            charset("text/plain; charset=utf-8").as_deref(),
+            Some("utf-8")
+        );
+        assert_eq!(
+            charset("text/plain;CHARSET=Latin1").as_deref(),
+            Some("latin1")
+        );
+        assert_eq!(
+            charset("text/html; q=1; charset=\"windows-1252\" ").as_deref(),
+            Some("windows-1252")
+        );
+
+        assert_eq!(charset("text/plain"), None);
+        assert_eq!(charset("text/plain; charset="), None);
+        assert_eq!(charset("text/plain; format=flowed"), None);
+        assert_eq!(Blob::empty().charset(), None);
+    }
+
+    #[tokio::test]
+    async fn from() {
+        let blob = Blob::from("hello");
+
+        assert_eq!(blob.data.len(), 1);
+        assert_eq!(blob.ty(), "");
+        assert_eq!(blob.text().await.unwrap(), "hello");
+
+        let blob: Blob = String::from("h\u{e9}llo").into();
+        assert_eq!(blob.size(), 6);
+        assert_eq!(blob.text().await.unwrap(), "h\u{e9}llo");
+
+        let blob: Blob = DATA.into();
+        assert_eq!(blob.text().await.unwrap(), TEXT);
+
+        let blob: Blob = DATA.to_vec().into();
+        assert_eq!(blob.text().await.unwrap(), TEXT);
+    }
+
+    #[test]
+    fn hash() {
+        let three = Blob::new(vec![&b"Second"[..], b" ", b"line"], None);
+        let single = Blob::new(vec![&b"Second line"[..]], None);
+        let slice = Blob::new(vec![DATA, DATA], None).slice(12, Some(23), None);
+
+        let set: HashSet<Blob> = [three, single, slice].into_iter().collect();
+        assert_eq!(set.len(), 1);
+
+        // Blobs that span multiple hash blocks with different part boundaries.
+        let bytes: Vec<u8> = (0..=255).cycle().take(1000).collect();
+        let chunked = Blob::new(bytes.chunks(7), None);
+        let single = Blob::new(vec![bytes.clone()], None);
+
+        let set: HashSet<Blob> = [chunked, single].into_iter().collect();
+        assert_eq!(set.len(), 1);
+
+        // Different contents or types hash to different entries.
+        let set: HashSet<Blob> = [
+            Blob::from("abc"),
+            Blob::from("abd"),
+            Blob::new(
+                vec!["abc"],
+                Some(BlobOptions::new(
+                    LineEndings::Transparent,
+                    Some("text/plain".into()),
+                )),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn hash_map_key() {
+        let hash = |blob: &Blob| {
+            let mut hasher = DefaultHasher::new();
+            blob.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let mut map = HashMap::new();
+        map.insert(Blob::from("payload"), 1);
+
+        // Blobs built through any path that are equal find the same entry.
+        let mut builder = Blob::builder();
+        builder
+            .push_str("pay")
+            .push_bytes(b"lo".to_vec())
+            .push_str("ad");
+
+        let built = builder.build();
+        let nested = Blob::from_parts([Blob::from("xpayload").slice(1, None, None)], None);
+
+        for key in [&built, &nested] {
+            assert_eq!(hash(key), hash(&Blob::from("payload")));
+            assert_eq!(map.get(key), Some(&1));
+        }
+
+        // The line ending option of a blob is not part of its identity.
+        let native = Blob::new(
+            vec!["payload"],
+            Some(BlobOptions::new(LineEndings::Native, None)),
+        );
+
+        *map.entry(native).or_default() += 1;
+
+        assert_eq!(map.len(), 1);
+        assert_eq!(map[&built], 2);
+    }
+
+    #[test]
+    fn iter() {
+        let blob = Blob::new(vec![&b"ABC"[..], b"", b"DEFG", DATA], None);
+
+        for slice in [
+            blob.clone(),
+            blob.slice(2, Some(8), None),
+            blob.slice(3, Some(7), None),
+            blob.slice(-5, None, None),
+            blob.slice(4, Some(4), None),
+        ] {
+            assert_eq!(slice.iter().count(), slice.size());
+            assert_eq!(slice.iter().collect::<Vec<_>>(), slice.coalesce());
+        }
+    }
+
+    #[test]
+    fn from_owned() {
+        // Owned buffers are moved into the blob without being copied.
+        let bytes = Vec::with_capacity(64);
+        let ptr = bytes.as_ptr();
+        let blob = Blob::from(bytes);
+
+        assert_eq!(blob.data[0].as_ptr(), ptr);
+        assert!(matches!(&*blob.data[0].bytes, Buffer::Heap(bytes) if bytes.capacity() == 64));
+
+        let text = String::with_capacity(64) + "hello";
+        let ptr = text.as_ptr();
+        let blob = Blob::from(text);
+
+        assert_eq!(blob.data[0].as_ptr(), ptr);
+        assert!(matches!(&*blob.data[0].bytes, Buffer::Heap(bytes) if bytes.capacity() == 64));
+
+        let text = String::from("{}");
+        let ptr = text.as_ptr();
+        let blob = Blob::from((text, "Application/JSON"));
+
+        assert_eq!(blob.data[0].as_ptr(), ptr);
+        assert_eq!(blob.ty(), "application/json");
+        assert_eq!(blob.to_vec(), b"{}");
+    }
+
+    #[test]
+    fn paths_agree() {
+        use std::io::Read;
+
+        let blob = Blob::new(vec![&b"ABC"[..], b"", b"DEFG", DATA, b"HI"], None);
+
+        for slice in [
+            blob.slice(1, Some(-1), None),
+            blob.slice(3, Some(7), None),
+            blob.slice(5, None, None).slice(2, Some(40), None),
+        ] {
+            let coalesced = slice.coalesce();
+            let mut read = Vec::new();
+
+            slice.reader().read_to_end(&mut read).unwrap();
+
+            assert_eq!(coalesced.len(), slice.size());
+            assert_eq!(slice.chunks().collect::<Vec<_>>().concat(), coalesced);
+            assert_eq!(slice.iter().collect::<Vec<_>>(), coalesced);
+            assert_eq!(read, coalesced);
+        }
+    }
+
+    #[test]
+    fn cursor() {
+        let data = vec![b"ABC".to_vec(), b"DEF".to_vec(), b"GHI".to_vec()];
+        let collect = |range| {
+            let mut cursor = Cursor::new(range);
+            std::iter::from_fn(|| cursor.next_chunk(&data)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(collect((0, 9)), [b"ABC", b"DEF", b"GHI"]);
+        assert_eq!(collect((2, 7)), [&b"C"[..], b"DEF", b"G"]);
+        assert_eq!(collect((3, 6)), [b"DEF"]);
+        assert!(collect((4, 4)).is_empty());
+    }
+
+    #[test]
+    fn cursor_is_done() {
+        let data = vec![b"ABC".to_vec(), b"DEF".to_vec(), b"GHI".to_vec()];
+        let mut cursor = Cursor::new((2, 7));
+
+        assert_eq!(cursor.next_chunk(&data), Some(&b"C"[..]));
+        assert_eq!(cursor.next_chunk(&data), Some(&b"DEF"[..]));
+        assert!(!cursor.is_done(&data));
+
+        assert_eq!(cursor.next_chunk(&data), Some(&b"G"[..]));
+        assert!(cursor.is_done(&data));
+
+        assert_eq!(cursor.next_chunk(&data), None);
+    }
+
+    #[tokio::test]
+    async fn multipart() {
+        let blob = Blob::new(vec![DATA, DATA], None);
+        let mut data = DATA.to_vec();
+
+        data.extend_from_slice(DATA);
+
+        assert_eq!(blob.text().await.unwrap().as_bytes(), data);
+    }
+
+    #[test]
+    fn coalesce_part_edges() {
+        let blob = Blob::new(vec![&b"ABC"[..], b"DEF", b"GHI"], None);
+
+        // Views that start and end exactly on part boundaries.
+        assert_eq!(blob.slice(0, Some(3), None).coalesce(), b"ABC");
+        assert_eq!(blob.slice(3, Some(6), None).coalesce(), b"DEF");
+        assert_eq!(blob.slice(3, Some(9), None).coalesce(), b"DEFGHI");
+        assert_eq!(blob.slice(6, None, None).coalesce(), b"GHI");
+
+        // Views that start or end mid-part.
+        assert_eq!(blob.slice(2, Some(6), None).coalesce(), b"CDEF");
+        assert_eq!(blob.slice(3, Some(7), None).coalesce(), b"DEFG");
+        assert_eq!(blob.slice(4, Some(5), None).coalesce(), b"E");
+
+        // Empty views on a part boundary.
+        assert!(blob.slice(3, Some(3), None).coalesce().is_empty());
+        assert!(blob.slice(9, None, None).coalesce().is_empty());
+    }
+
+    #[test]
+    fn coalesce_past_end() {
+        let mut blob = Blob::new(vec![DATA, DATA], None);
+        let len = DATA.len() * 2;
+
+        // Views are clamped by slice, so construct an out of range view by hand.
+        blob.view = Some((DATA.len() - 4, len + 100));
+
+        let bytes = blob.coalesce();
+        let mut expected = DATA[DATA.len() - 4..].to_vec();
+
+        expected.extend_from_slice(DATA);
+
+        assert_eq!(bytes.len(), DATA.len() + 4);
+        assert_eq!(bytes, expected);
+        assert!(!bytes.ends_with(&[0]));
+    }
+
+    #[tokio::test]
+    async fn slice_part_boundary() {
+        let blob = Blob::new(vec![b"ABC", b"DEF"], None);
+        let slice = blob.slice(3, Some(6), None);
+
+        // The first byte of the view is the first byte of the second part.
+        assert_eq!(slice.size(), 3);
+        assert_eq!(slice.text().await.unwrap(), "DEF");
+        assert_eq!(slice.iter().collect::<Vec<_>>(), b"DEF");
+
+        // Empty parts that sit on the boundary do not shift the view.
+        let blob = Blob::new(vec![&b"ABC"[..], b"", b"", b"DEF"], None);
+
+        assert_eq!(blob.slice(3, Some(6), None).text().await.unwrap(), "DEF");
+        assert_eq!(blob.slice(2, Some(4), None).text().await.unwrap(), "CD");
+        assert_eq!(blob.slice(3, Some(4), None).text().await.unwrap(), "D");
+    }
+
+    #[test]
+    fn read_with_progress() {
+        let blob = Blob::new(vec![DATA, DATA, DATA], None).slice(10, Some(-10), None);
+        let total = blob.size();
+        let mut events = Vec::new();
+
+        let bytes = blob.read_with_progress(|read, total| events.push((read, total)));
+
+        assert_eq!(bytes, blob.to_vec());
+        assert_eq!(events.len(), 3);
+        assert_eq!(events.last(), Some(&(total, total)));
+        assert!(events.iter().all(|&(_, n)| n == total));
+        assert!(events.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+        // An empty blob reports completion exactly once.
+        let mut events = Vec::new();
+
+        Blob::empty().read_with_progress(|read, total| events.push((read, total)));
+
+        assert_eq!(events, [(0, 0)]);
+    }
+
+    #[tokio::test]
+    async fn concat() {
+        let opts = BlobOptions::new(LineEndings::Transparent, Some("text/plain".into()));
+        let first = Blob::new(vec![&b"one "[..], b"two "], Some(opts));
+        let second = Blob::from("three four five").slice(6, Some(11), None);
+        let blob = Blob::concat(&[first.clone(), second.clone(), Blob::empty()]);
+
+        assert_eq!(blob.text().await.unwrap(), "one two four ");
+        assert_eq!(blob.ty(), "text/plain");
+        assert_eq!(blob.size(), first.size() + second.size());
+        assert!(Arc::ptr_eq(&blob.data[0].bytes, &first.data[0].bytes));
+
+        // The Content-Type of the first blob is used even if it is empty.
+        let blob = Blob::concat(&[second.clone(), first.clone()]);
+
+        assert_eq!(blob.text().await.unwrap(), "four one two ");
+        assert_eq!(blob.ty(), "");
+        assert_eq!(Blob::concat(&[]), Blob::empty());
+    }
+
+    #[tokio::test]
+    async fn with_appended() {
+        let opts = BlobOptions::new(LineEndings::Crlf, Some("text/plain".into()));
+        let blob = Blob::from_strings(vec!["a\n"], Some(opts));
+        let appended = blob.with_appended("b\n").with_appended(vec![b'c']);
+
+        // The appended parts are binary and are not converted.
+        assert_eq!(appended.text().await.unwrap(), "a\r\nb\nc");
+        assert_eq!(appended.ty(), "text/plain");
+        assert_eq!(blob.text().await.unwrap(), "a\r\n");
+        assert!(Arc::ptr_eq(&appended.data[0].bytes, &blob.data[0].bytes));
+
+        // Only the bytes in view of a slice are included.
+        let slice = appended.slice(1, Some(4), None).with_appended("!");
+        assert_eq!(slice.text().await.unwrap(), "\r\nb!");
     }
 
     #[tokio::test]
@@ -343,10 +1988,328 @@ mod tests {
         assert_eq!(slice.text().await.unwrap(), "Second line");
     }
 
+    #[tokio::test]
+    async fn slice_clamped() {
+        let blob = Blob::new(vec![DATA, DATA], None);
+        let len = DATA.len() * 2;
+
+        // The start index is past the end of the blob.
+        let slice = blob.slice(len as i64 + 10, None, None);
+
+        assert_eq!(slice.size(), 0);
+        assert_eq!(slice.text().await.unwrap(), "");
+
+        // The end index is past the end of the blob.
+        let slice = blob.slice(10, Some(10_000), None);
+
+        assert_eq!(slice.size(), len - 10);
+        assert_eq!(slice.coalesce().len(), len - 10);
+        assert_eq!(
+            slice.text().await.unwrap().as_bytes(),
+            &blob.coalesce()[10..]
+        );
+
+        // The end index is omitted.
+        let slice = blob.slice(DATA.len() as i64 - 4, None, None);
+
+        assert_eq!(slice.size(), DATA.len() + 4);
+        assert_eq!(
+            slice.text().await.unwrap().as_bytes(),
+            &blob.coalesce()[DATA.len() - 4..]
+        );
+
+        // The end index precedes the start index.
+        assert_eq!(blob.slice(20, Some(10), None).size(), 0);
+    }
+
+    #[tokio::test]
+    async fn slice_normalized() {
+        let blob = Blob::new(vec![DATA.to_vec()], None);
+
+        // An inverted range produces an empty view.
+        let slice = blob.slice(50, Some(10), None);
+
+        assert_eq!(slice.size(), 0);
+        assert_eq!(slice.view, Some((DATA.len(), DATA.len())));
+
+        // An end index past the end of the blob is clamped to the size.
+        let slice = blob.slice(0, Some(9999), None);
+
+        assert_eq!(slice.size(), DATA.len());
+        assert_eq!(slice.view, Some((0, DATA.len())));
+        assert_eq!(slice.text().await.unwrap().as_bytes(), DATA);
+
+        // An out of range start index is clamped to the size.
+        let slice = blob.slice(9999, Some(10_000), None);
+
+        assert_eq!(slice.size(), 0);
+        assert_eq!(slice.text().await.unwrap(), "");
+    }
+
+    #[test]
+    fn format() {
+        let opts = BlobOptions::new(LineEndings::Transparent, Some("text/plain".into()));
+        let blob = Blob::new(vec![&b"Hello"[..], b", ", b"world!"], Some(opts));
+
+        assert_eq!(blob.to_string(), r#"Blob { size: 13, type: "text/plain" }"#);
+        assert_eq!(
+            format!("{blob:?}"),
+            r#"Blob { size: 13, type: "text/plain", parts: 3, view: None }"#
+        );
+
+        // The contents of the parts are never printed.
+        let slice = blob.slice(7, Some(12), None);
+
+        assert_eq!(slice.to_string(), r#"Blob { size: 5, type: "" }"#);
+        assert_eq!(
+            format!("{slice:?}"),
+            r#"Blob { size: 5, type: "", parts: 3, view: Some((7, 12)) }"#
+        );
+    }
+
+    #[test]
+    fn size_inverted_view() {
+        let mut blob = Blob::new(vec![DATA.to_vec()], None);
+
+        blob.view = Some((20, 10));
+
+        assert_eq!(blob.size(), 0);
+        assert_eq!(
+            blob.try_size(),
+            Err(BlobError::RangeError {
+                start: 20,
+                end: 10,
+                size: DATA.len(),
+            })
+        );
+
+        // A view that extends past the end of the data is also inconsistent.
+        blob.view = Some((10, DATA.len() + 1));
+        assert!(blob.try_size().is_err());
+
+        blob.view = Some((10, 20));
+        assert_eq!(blob.try_size(), Ok(10));
+        assert_eq!(Blob::empty().try_size(), Ok(0));
+    }
+
+    #[test]
+    fn size_cached() {
+        let parts: Vec<_> = (0..1000).map(|n| vec![b'x'; n % 7]).collect();
+        let sum = parts.iter().map(Vec::len).sum::<usize>();
+        let blob = Blob::new(parts, None);
+
+        assert_eq!(blob.len, sum);
+        assert_eq!(blob.size(), sum);
+        assert_eq!(blob.size(), blob.to_vec().len());
+
+        // Slices share the total of their parent and clamp against it, even
+        // when they are nested.
+        let slice = blob.slice(10, Some(-10), None);
+        let nested = slice.slice(-50, None, None).slice(0, Some(10_000), None);
+
+        assert_eq!(slice.len, sum);
+        assert_eq!(slice.size(), sum - 20);
+        assert_eq!(nested.len, sum);
+        assert_eq!(nested.size(), 50);
+        assert_eq!(nested.range(), (sum - 60, sum - 10));
+
+        // A blob built from a nested view only counts the bytes in view.
+        let blob = Blob::from_parts([&nested, &nested], None);
+
+        assert_eq!(blob.len, 100);
+        assert_eq!(blob.size(), 100);
+    }
+
+    #[tokio::test]
+    async fn compact() {
+        let opts = BlobOptions::new(LineEndings::Transparent, Some("text/plain".into()));
+        let parts: Vec<_> = (0..5000).map(|n| (n % 10).to_string()).collect();
+        let blob = Blob::new(parts, Some(opts));
+        let compacted = blob.compact();
+
+        assert_eq!(blob.part_count(), 5000);
+        assert_eq!(compacted.part_count(), 1);
+        assert_eq!(compacted.ty(), "text/plain");
+        assert_eq!(compacted.text().await.unwrap(), blob.text().await.unwrap());
+
+        // Only the bytes in view are kept, and none are shared with self.
+        let slice = blob.slice(10, Some(25), None).compact();
+
+        assert_eq!(slice.part_sizes(), [15]);
+        assert_eq!(slice.text().await.unwrap(), "012345678901234");
+        assert!(!Arc::ptr_eq(&compacted.data[0].bytes, &blob.data[0].bytes));
+
+        assert_eq!(blob.slice(3, Some(3), None).compact().part_count(), 0);
+        assert_eq!(Blob::empty().compact(), Blob::empty());
+    }
+
+    #[test]
+    fn part_sizes() {
+        let blob = Blob::new(vec![&b"ABC"[..], b"DEFGH", b"I"], None);
+
+        assert_eq!(blob.part_count(), 3);
+        assert_eq!(blob.part_sizes(), [3, 5, 1]);
+
+        // A slice reports the parts of its parent before they are clipped.
+        let slice = blob.slice(4, Some(6), None);
+
+        assert_eq!(slice.part_count(), 3);
+        assert_eq!(slice.part_sizes(), [3, 5, 1]);
+
+        assert_eq!(Blob::empty().part_count(), 0);
+        assert!(Blob::empty().part_sizes().is_empty());
+    }
+
+    #[tokio::test]
+    async fn slice_negative() {
+        let blob = Blob::new(vec![DATA.to_vec()], None);
+
+        // The last five bytes of the blob.
+        let slice = blob.slice(-5, None, None);
+        assert_eq!(slice.text().await.unwrap(), " line");
+
+        // Everything except for the last byte of the blob.
+        let slice = blob.slice(0, Some(-1), None);
+        assert_eq!(
+            slice.text().await.unwrap().as_bytes(),
+            &DATA[..DATA.len() - 1]
+        );
+
+        // A negative start that exceeds the size of the blob is clamped to 0.
+        let slice = blob.slice(-1000, Some(5), None);
+        assert_eq!(slice.text().await.unwrap(), "First");
+
+        // A negative end that exceeds the size of the blob is clamped to 0.
+        assert_eq!(blob.slice(0, Some(i64::MIN), None).size(), 0);
+    }
+
+    #[tokio::test]
+    async fn slice_negative_range() {
+        let blob = Blob::new(vec![DATA, DATA], None);
+        let bytes = blob.coalesce();
+        let len = bytes.len();
+
+        let slice = blob.slice(-10, None, None);
+        assert_eq!(slice.text().await.unwrap().as_bytes(), &bytes[len - 10..]);
+
+        let slice = blob.slice(-20, Some(-5), None);
+        assert_eq!(
+            slice.text().await.unwrap().as_bytes(),
+            &bytes[len - 20..len - 5]
+        );
+
+        // Mixing a positive start with a negative end.
+        let slice = blob.slice(12, Some(-(len as i64) + 23), None);
+        assert_eq!(slice.text().await.unwrap(), "Second line");
+    }
+
+    #[tokio::test]
+    async fn slice_with_parent_type() {
+        let csv = "id,name\n1,alpha\n2,beta\n";
+        let opts = BlobOptions::new(LineEndings::Transparent, Some("text/csv".into()));
+        let blob = Blob::new(vec![csv], Some(opts));
+
+        let row = blob.slice_with_parent_type(8, Some(16));
+
+        assert_eq!(row.ty(), "text/csv");
+        assert_eq!(row.text().await.unwrap(), "1,alpha\n");
+
+        // Slices of slices continue to inherit the type.
+        assert_eq!(row.slice_with_parent_type(0, Some(1)).ty(), "text/csv");
+
+        // An explicit type or the empty default are still available via slice.
+        assert_eq!(blob.slice(0, None, None).ty(), "");
+        assert_eq!(
+            blob.slice(0, None, Some("text/plain".into())).ty(),
+            "text/plain"
+        );
+    }
+
+    #[tokio::test]
+    async fn slice_endings() {
+        let opts = BlobOptions::new(LineEndings::Native, None);
+
+        crate::set_native_newline(Some(Newline::Crlf));
+        let blob = Blob::from_strings(vec!["one\ntwo\rthree\n"], Some(opts));
+        crate::set_native_newline(None);
+
+        // Line endings are converted when the blob is constructed, so a slice
+        // returns the converted bytes and keeps the setting of its parent.
+        let slice = blob.slice(5, Some(-2), None);
+
+        assert_eq!(slice.opts.endings, LineEndings::Native);
+        assert_eq!(slice.text().await.unwrap(), "two\r\nthree");
+        assert_eq!(
+            slice.slice(3, None, None).text().await.unwrap(),
+            "\r\nthree"
+        );
+    }
+
+    #[tokio::test]
+    async fn slice_nested() {
+        let blob = Blob::new(vec![DATA, DATA], None);
+        let outer = blob.slice(12, Some(60), None);
+        let inner = outer.slice(10, Some(20), None);
+
+        assert_eq!(inner.size(), 10);
+        assert_eq!(inner.text().await.unwrap().as_bytes(), &DATA[22..32]);
+
+        // The second slice starts in the first part and ends in the second.
+        let inner = outer.slice(30, None, None);
+        let mut expected = DATA[42..].to_vec();
+
+        expected.extend_from_slice(&DATA[..13]);
+
+        assert_eq!(inner.size(), 18);
+        assert_eq!(inner.text().await.unwrap().as_bytes(), expected);
+    }
+
+    #[tokio::test]
+    async fn slice_nested_deep() {
+        let blob = Blob::new(vec![DATA, DATA], None);
+
+        // "Second line\nThird line"
+        let first = blob.slice(12, Some(34), None);
+        // "line\nThird"
+        let second = first.slice(7, Some(17), None);
+        // "Third"
+        let third = second.slice(5, None, None);
+
+        assert_eq!(first.text().await.unwrap(), "Second line\nThird line");
+        assert_eq!(second.text().await.unwrap(), "line\nThird");
+        assert_eq!(third.text().await.unwrap(), "Third");
+        assert_eq!(third.view, Some((24, 29)));
+
+        // Slicing from the start of a nested slice produces the same view.
+        assert_eq!(
+            blob.slice(12, Some(23), None).slice(0, Some(6), None).view,
+            Some((12, 18))
+        );
+    }
+
+    #[tokio::test]
+    async fn slice_nested_overrun() {
+        let blob = Blob::new(vec![DATA, DATA], None);
+        let slice = blob.slice(12, Some(23), None);
+
+        // The end index runs past the end of the parent slice. The result is
+        // clamped to the parent's view rather than the underlying data.
+        let nested = slice.slice(7, Some(100), None);
+
+        assert_eq!(nested.size(), 4);
+        assert_eq!(nested.text().await.unwrap(), "line");
+
+        // The start index is past the end of the parent slice.
+        let nested = slice.slice(20, None, None);
+
+        assert_eq!(nested.size(), 0);
+        assert_eq!(nested.text().await.unwrap(), "");
+    }
+
     #[tokio::test]
     async fn text_native() {
-        let blob = Blob::new(
-            vec![DATA.to_vec()],
+        let blob = Blob::from_strings(
+            vec![TEXT],
             Some(BlobOptions::new(LineEndings::Native, None)),
         );
 
@@ -363,6 +2326,281 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn text_native_slice() {
+        let blob = Blob::from_strings(
+            vec![TEXT],
+            Some(BlobOptions::new(LineEndings::Native, None)),
+        );
+
+        // "Second line\nThird line\r\n" with native line endings. The indices
+        // are relative to the normalized bytes.
+        #[cfg(target_os = "windows")]
+        assert_eq!(
+            blob.slice(12, Some(37), None).text().await.unwrap(),
+            "Second line\r\nThird line\r\n"
+        );
+
+        #[cfg(not(target_os = "windows"))]
+        assert_eq!(
+            blob.slice(11, Some(34), None).text().await.unwrap(),
+            "Second line\nThird line\n"
+        );
+    }
+
+    #[test]
+    fn normalize_mixed() {
+        let input = "one\rtwo\nthree\r\nfour\r\rfive\n\r";
+
+        assert_eq!(
+            normalize_line_endings(input, Newline::Crlf).unwrap(),
+            "one\r\ntwo\r\nthree\r\nfour\r\n\r\nfive\r\n\r\n"
+        );
+
+        assert_eq!(
+            normalize_line_endings(input, Newline::Lf).unwrap(),
+            "one\ntwo\nthree\nfour\n\nfive\n\n"
+        );
+    }
+
+    #[test]
+    fn normalize_trailing_cr() {
+        let crlf = |input| normalize_line_endings(input, Newline::Crlf).unwrap();
+
+        assert_eq!(crlf("\r"), "\r\n");
+        assert_eq!(crlf("line1\rline2\r"), "line1\r\nline2\r\n");
+        assert_eq!(crlf("a\r\n\r"), "a\r\n\r\n");
+        assert_eq!(crlf("a\n\r\n\r"), "a\r\n\r\n\r\n");
+
+        let lf = |input| normalize_line_endings(input, Newline::Lf).unwrap();
+
+        assert_eq!(lf("\r"), "\n");
+        assert_eq!(lf("line1\rline2\r"), "line1\nline2\n");
+        assert_eq!(lf("a\r\n\r"), "a\n\n");
+        assert_eq!(lf("a\n\r\n\r"), "a\n\n\n");
+    }
+
+    #[test]
+    fn normalize_noop() {
+        for newline in [Newline::Lf, Newline::Crlf] {
+            assert_eq!(normalize_line_endings("no line endings", newline), None);
+            assert_eq!(normalize_line_endings("", newline), None);
+        }
+
+        assert_eq!(
+            normalize_line_endings("one\r\ntwo\r\n", Newline::Crlf),
+            None
+        );
+        assert_eq!(normalize_line_endings("one\ntwo\n", Newline::Lf), None);
+    }
+
+    #[test]
+    fn normalize_crlf_no_copy() {
+        let crlf = BlobOptions::new(LineEndings::Crlf, None);
+        let input = String::from("one\r\ntwo\r\n\r\nthree");
+        let ptr = input.as_ptr();
+        let blob = Blob::from_parts([input], Some(crlf));
+
+        // Text that already uses CRLF is moved into the blob as is rather than
+        // being copied into a new buffer.
+        assert_eq!(blob.data[0].bytes.as_ptr(), ptr);
+        assert_eq!(blob.to_vec(), b"one\r\ntwo\r\n\r\nthree");
+
+        // The same holds for native line endings on Windows.
+        #[cfg(target_os = "windows")]
+        {
+            let native = BlobOptions::new(LineEndings::Native, None);
+            let input = String::from("one\r\ntwo\r\n");
+            let ptr = input.as_ptr();
+            let blob = Blob::from_parts([input], Some(native));
+
+            assert_eq!(blob.data[0].bytes.as_ptr(), ptr);
+        }
+    }
+
+    #[tokio::test]
+    async fn endings_explicit() {
+        let lf = Some(BlobOptions::new(LineEndings::Lf, None));
+        let crlf = Some(BlobOptions::new(LineEndings::Crlf, None));
+
+        // Existing CRLF pairs are not doubled up when the target is CRLF.
+        let blob = Blob::from_strings(vec!["one\ntwo\r\nthree\rfour"], crlf.clone());
+        let text = blob.text().await.unwrap();
+
+        assert_eq!(text, "one\r\ntwo\r\nthree\r\nfour");
+        assert!(!text.contains("\r\r\n"));
+
+        // Round-trip LF -> CRLF -> LF.
+        let input = "alpha\nbeta\n\ngamma\n";
+        let converted = Blob::from_strings(vec![input], crlf);
+        let restored = Blob::from_parts([converted.text().await.unwrap()], lf);
+
+        assert_eq!(converted.size(), input.len() + 4);
+        assert_eq!(restored.text().await.unwrap(), input);
+
+        // Binary parts are never converted.
+        let blob = Blob::new(
+            vec![&b"a\nb"[..]],
+            Some(BlobOptions::new(LineEndings::Crlf, None)),
+        );
+
+        assert_eq!(blob.to_vec(), b"a\nb");
+    }
+
+    #[tokio::test]
+    async fn text_native_mixed() {
+        let blob = Blob::from_strings(
+            vec!["classic\rmac", "\nunix\r\nwindows\r"],
+            Some(BlobOptions::new(LineEndings::Native, None)),
+        );
+
+        #[cfg(target_os = "windows")]
+        assert_eq!(
+            blob.text().await.unwrap(),
+            "classic\r\nmac\r\nunix\r\nwindows\r\n"
+        );
+
+        #[cfg(not(target_os = "windows"))]
+        assert_eq!(blob.text().await.unwrap(), "classic\nmac\nunix\nwindows\n");
+    }
+
+    #[tokio::test]
+    async fn native_size() {
+        let opts = BlobOptions::new(LineEndings::Native, None);
+        let blob = Blob::from_strings(vec![TEXT], Some(opts.clone()));
+        let text = blob.text().await.unwrap();
+
+        // The stored bytes are already normalized, so every accessor agrees.
+        assert_eq!(blob.size(), text.len());
+        assert_eq!(blob.to_vec(), text.as_bytes());
+
+        #[cfg(not(target_os = "windows"))]
+        assert_eq!(blob.size(), DATA.len() - 2);
+
+        // Binary parts are never converted.
+        let blob = Blob::new(vec![DATA], Some(opts));
+
+        assert_eq!(blob.size(), DATA.len());
+        assert_eq!(blob.text().await.unwrap(), TEXT);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[tokio::test]
+    async fn native_size_crlf() {
+        let native = Some(BlobOptions::new(LineEndings::Native, None));
+        let input = "a\r\nb\r\nc\r\n";
+
+        // Each `\r\n` pair is stored as a single `\n`.
+        let blob = Blob::from_strings(vec![input], native.clone());
+
+        assert_eq!(blob.size(), 6);
+        assert_eq!(blob.to_vec(), b"a\nb\nc\n");
+        assert_eq!(blob.slice(2, Some(4), None).text().await.unwrap(), "b\n");
+
+        // The same input is stored unchanged without native endings.
+        assert_eq!(Blob::from_strings(vec![input], None).size(), 9);
+
+        // A pair split across two string parts is converted as two line
+        // endings because each part is normalized independently.
+        let blob = Blob::from_strings(vec!["a\r", "\nb"], native);
+
+        assert_eq!(blob.size(), 4);
+        assert_eq!(blob.text().await.unwrap(), "a\n\nb");
+    }
+
+    #[tokio::test]
+    async fn text_large() {
+        let line = "Lorem ipsum dolor sit amet, consectetur adipiscing elit.\r\n";
+        let opts = Some(BlobOptions::new(LineEndings::Lf, None));
+
+        // Roughly 4 MiB of text split into 64 string parts.
+        let part = line.repeat(1024);
+        let blob = Blob::from_strings(vec![part.as_str(); 64], opts);
+        let text = blob.text().await.unwrap();
+
+        assert_eq!(text.len(), (line.len() - 1) * 1024 * 64);
+        assert_eq!(text.lines().count(), 1024 * 64);
+        assert!(!text.contains('\r'));
+
+        // Line endings are converted at construction, so text only has to copy
+        // the parts into a single buffer of the exact size and validate it in
+        // place. A second full-size allocation would show up as extra capacity
+        // or as a mismatch with size.
+        assert_eq!(text.capacity(), blob.size());
+
+        let slice = blob.slice(1 << 20, Some(3 << 20), None);
+        assert_eq!(slice.text().await.unwrap().capacity(), 2 << 20);
+    }
+
+    #[tokio::test]
+    async fn text_split_code_point() {
+        let text = "a\u{e9}\u{20AC}\u{1F600}z";
+        let bytes = text.as_bytes();
+
+        // Every split of every multi-byte sequence decodes normally into a
+        // string of the exact size.
+        for split in 1..bytes.len() {
+            let blob = Blob::new(vec![&bytes[..split], &bytes[split..]], None);
+            let decoded = blob.text().await.unwrap();
+
+            assert_eq!(decoded, text, "split at {split}");
+            assert_eq!(decoded.capacity(), bytes.len());
+        }
+
+        // A 4-byte sequence split evenly across two binary parts.
+        let blob = Blob::new(vec![vec![0xF0, 0x9F], vec![0x98, 0x80]], None);
+
+        assert_eq!(blob.text().await.unwrap(), "\u{1F600}");
+        assert_eq!(blob.text_lossy().await, "\u{1F600}");
+        assert!(blob.is_valid_utf8());
+    }
+
+    #[tokio::test]
+    async fn text_lossy() {
+        // A 3-byte sequence split across a part boundary decodes normally.
+        let blob = Blob::new(vec![&b"a\xE2\x82"[..], b"\xACb"], None);
+
+        assert_eq!(blob.text_lossy().await, "a\u{20AC}b");
+        assert_eq!(blob.text().await.unwrap(), "a\u{20AC}b");
+
+        // A view that cuts the sequence short is replaced.
+        assert_eq!(blob.slice(0, Some(3), None).text_lossy().await, "a\u{FFFD}");
+        assert!(blob.slice(0, Some(3), None).text().await.is_err());
+
+        // Each orphaned continuation byte is replaced on its own.
+        assert_eq!(
+            blob.slice(2, None, None).text_lossy().await,
+            "\u{FFFD}\u{FFFD}b"
+        );
+
+        // Invalid bytes in binary parts are replaced as well.
+        let blob = Blob::new(vec![&b"ok\xFF"[..], b"\r\n"], None);
+
+        assert_eq!(blob.text_lossy().await, "ok\u{FFFD}\r\n");
+    }
+
+    #[tokio::test]
+    async fn strip_bom() {
+        let opts = BlobOptions::default().with_strip_bom(true);
+
+        // A BOM split across the first parts of a blob is still detected.
+        let blob = Blob::new(vec![&b"\xEF"[..], b"\xBB\xBFhi"], Some(opts.clone()));
+
+        assert_eq!(blob.text().await.unwrap(), "hi");
+        assert_eq!(blob.text_lossy().await, "hi");
+
+        // The option is preserved by slices.
+        assert_eq!(blob.slice(0, Some(4), None).text().await.unwrap(), "h");
+
+        // Only a leading BOM is stripped.
+        let blob = Blob::new(vec![&b"hi\xEF\xBB\xBF"[..]], Some(opts));
+        assert_eq!(blob.text().await.unwrap(), "hi\u{feff}");
+
+        // The BOM is kept by default.
+        let blob = Blob::new(vec![&b"\xEF\xBB\xBFhi"[..]], None);
+        assert_eq!(blob.text().await.unwrap(), "\u{feff}hi");
+    }
+
     #[tokio::test]
     async fn text_transparent() {
         let blob = Blob::new(vec![DATA.to_vec()], None);
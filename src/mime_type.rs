@@ -0,0 +1,86 @@
+use mime::Mime;
+
+use crate::Blob;
+
+impl Blob {
+    /// Parses the type of self as a MIME type.
+    ///
+    /// Returns `None` if self does not have a type or the type is not a valid
+    /// MIME type. Parameters such as `charset` are available through the
+    /// returned `Mime`, and compare without regard to the spacing of the
+    /// original string.
+    ///
+    pub fn ty_parsed(&self) -> Option<Mime> {
+        self.ty().parse().ok()
+    }
+
+    /// Returns true if the type of self is a `text/*` type.
+    ///
+    pub fn is_text(&self) -> bool {
+        self.ty_parsed()
+            .is_some_and(|mime| mime.type_() == mime::TEXT)
+    }
+
+    /// Returns true if the type of self is an `image/*` type.
+    ///
+    pub fn is_image(&self) -> bool {
+        self.ty_parsed()
+            .is_some_and(|mime| mime.type_() == mime::IMAGE)
+    }
+
+    /// Returns true if the type of self is JSON, either `application/json` or
+    /// a type with a `+json` suffix such as `application/ld+json`.
+    ///
+    pub fn is_json(&self) -> bool {
+        self.ty_parsed()
+            .is_some_and(|mime| mime.subtype() == mime::JSON || mime.suffix() == Some(mime::JSON))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Blob, BlobOptions, LineEndings};
+
+    fn with_type(ty: &str) -> Blob {
+        let opts = BlobOptions::new(LineEndings::Transparent, Some(ty.into()));
+        Blob::new(vec![&b"{}"[..]], Some(opts))
+    }
+
+    #[test]
+    fn ty_parsed() {
+        let compact = with_type("text/plain;charset=UTF-8").ty_parsed().unwrap();
+        let spaced = with_type("Text/Plain; Charset=utf-8").ty_parsed().unwrap();
+
+        assert_eq!(compact, spaced);
+        assert_eq!(compact.type_(), mime::TEXT);
+        assert_eq!(compact.subtype(), mime::PLAIN);
+        assert_eq!(compact.get_param(mime::CHARSET), Some(mime::UTF_8));
+        assert_eq!(compact.essence_str(), "text/plain");
+
+        let ld = with_type("application/ld+json").ty_parsed().unwrap();
+
+        assert_eq!(ld.subtype(), "ld");
+        assert_eq!(ld.suffix(), Some(mime::JSON));
+
+        // A missing or invalid type never panics.
+        assert_eq!(Blob::empty().ty_parsed(), None);
+        assert_eq!(with_type("not a mime type").ty_parsed(), None);
+        assert_eq!(with_type("text").ty_parsed(), None);
+    }
+
+    #[test]
+    fn predicates() {
+        assert!(with_type("text/csv; charset=utf-8").is_text());
+        assert!(!with_type("text/csv").is_image());
+        assert!(with_type("image/svg+xml").is_image());
+
+        assert!(with_type("application/json").is_json());
+        assert!(with_type("application/ld+json").is_json());
+        assert!(!with_type("text/plain").is_json());
+
+        let invalid = with_type("nonsense");
+
+        assert!(!invalid.is_text() && !invalid.is_image() && !invalid.is_json());
+        assert!(!Blob::empty().is_text());
+    }
+}
@@ -0,0 +1,110 @@
+use reqwest::multipart::Part;
+use reqwest::Body;
+
+use crate::{Blob, File};
+
+/// Converts a Blob into a part of a `reqwest` multipart form.
+///
+/// The MIME type of the part is the type of the blob, if it has one. The body
+/// of the part is streamed one part of the blob at a time rather than
+/// coalesced, and each part shares its buffer with the blob instead of being
+/// copied.
+///
+/// # Errors
+///
+/// Returns an error if the type of the blob is not a valid MIME type.
+///
+impl TryFrom<Blob> for Part {
+    type Error = reqwest::Error;
+
+    fn try_from(blob: Blob) -> Result<Self, Self::Error> {
+        let body = Body::wrap_stream(blob.body_stream());
+        let part = Part::stream_with_length(body, blob.size() as u64);
+
+        match blob.ty() {
+            "" => Ok(part),
+            ty => part.mime_str(ty),
+        }
+    }
+}
+
+/// Converts a File into a part of a `reqwest` multipart form with the name of
+/// the file.
+///
+/// # Errors
+///
+/// Returns an error if the type of the file is not a valid MIME type.
+///
+impl TryFrom<File> for Part {
+    type Error = reqwest::Error;
+
+    fn try_from(file: File) -> Result<Self, Self::Error> {
+        let name = file.name().to_owned();
+        Ok(Part::try_from(file.into_blob())?.file_name(name))
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use reqwest::multipart::{Form, Part};
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::{Blob, BlobOptions, File, LineEndings};
+
+    /// Uploads `form` to a mock server and returns the body of the request
+    /// that the server received.
+    async fn upload(form: Form) -> String {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        reqwest::Client::new()
+            .post(server.uri())
+            .multipart(form)
+            .send()
+            .await
+            .unwrap()
+            .error_for_status()
+            .unwrap();
+
+        let requests = server.received_requests().await.unwrap();
+        String::from_utf8(requests[0].body.clone()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn upload_file() {
+        let opts = BlobOptions::new(LineEndings::Transparent, Some("text/csv".into()));
+        let file = File::new(vec!["a,b\n", "1,2\n"], "report.csv", Some(opts));
+        let body = upload(Form::new().part("file", Part::try_from(file).unwrap())).await;
+
+        assert!(body.contains(
+            "Content-Disposition: form-data; name=\"file\"; filename=\"report.csv\"\r\n"
+        ));
+        assert!(body.contains("Content-Type: text/csv\r\n\r\na,b\n1,2\n\r\n"));
+    }
+
+    #[tokio::test]
+    async fn upload_blob() {
+        let blob = Blob::new(vec![&b"ABC"[..], b"DEFG", b"HI"], None).slice(2, Some(-1), None);
+        let form = Form::new()
+            .part("slice", Part::try_from(blob).unwrap())
+            .part("empty", Part::try_from(Blob::empty()).unwrap());
+
+        let body = upload(form).await;
+
+        // A blob without a type does not have a Content-Type header.
+        assert!(body.contains("Content-Disposition: form-data; name=\"slice\"\r\n\r\nCDEFGH\r\n"));
+        assert!(body.contains("Content-Disposition: form-data; name=\"empty\"\r\n\r\n\r\n"));
+        assert!(!body.contains("Content-Type"));
+    }
+
+    #[test]
+    fn invalid_type() {
+        let opts = BlobOptions::new(LineEndings::Transparent, Some("not a mime type".into()));
+        assert!(Part::try_from(Blob::new(vec![&b""[..]], Some(opts))).is_err());
+    }
+}
@@ -0,0 +1,146 @@
+use std::cell::{Cell, OnceCell};
+
+/// A line ending convention.
+///
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Newline {
+    /// A single line feed (`\n`), as used by Unix-like systems.
+    Lf,
+
+    /// A carriage return followed by a line feed (`\r\n`), as used by Windows.
+    Crlf,
+}
+
+impl Newline {
+    /// Returns the characters that make up a line ending of this convention.
+    ///
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::Crlf => "\r\n",
+        }
+    }
+}
+
+thread_local! {
+    /// A convention that takes precedence over the one detected for the host.
+    static OVERRIDE: Cell<Option<Newline>> = const { Cell::new(None) };
+
+    /// The convention of the host, detected the first time it is needed.
+    static DETECTED: OnceCell<Newline> = const { OnceCell::new() };
+}
+
+/// Overrides the convention that `LineEndings::Native` converts to for blobs
+/// constructed on the current thread. Pass `None` to restore the convention
+/// of the host system.
+///
+/// This is primarily useful for tests that need to exercise either
+/// convention regardless of the host they run on.
+///
+pub fn set_native_newline(newline: Option<Newline>) {
+    OVERRIDE.with(|cell| cell.set(newline));
+}
+
+/// Returns the line ending convention that `LineEndings::Native` converts to.
+///
+pub(crate) fn native() -> Newline {
+    OVERRIDE
+        .with(Cell::get)
+        .unwrap_or_else(|| DETECTED.with(|cell| *cell.get_or_init(detect)))
+}
+
+/// Returns the line ending convention of the target operating system.
+///
+#[cfg(not(all(target_arch = "wasm32", feature = "js")))]
+fn detect() -> Newline {
+    if cfg!(target_os = "windows") {
+        Newline::Crlf
+    } else {
+        Newline::Lf
+    }
+}
+
+/// Returns the line ending convention of the operating system that the
+/// JavaScript host is running on.
+///
+/// The target OS of a wasm module is always unknown, so the platform is read
+/// from `navigator` instead. The global object is used rather than `window`
+/// so that detection also works in workers. If `navigator` is not available,
+/// as is the case in some server-side runtimes, `Newline::Lf` is assumed.
+///
+#[cfg(all(target_arch = "wasm32", feature = "js"))]
+fn detect() -> Newline {
+    use js_sys::Reflect;
+    use wasm_bindgen::JsValue;
+
+    let lookup = |target: &JsValue, key: &str| {
+        Reflect::get(target, &JsValue::from_str(key))
+            .ok()
+            .filter(|value| !value.is_undefined() && !value.is_null())
+    };
+
+    let platform = lookup(&js_sys::global(), "navigator").and_then(|navigator| {
+        // Prefer the low-entropy platform hint of User-Agent Client Hints and
+        // fall back to the legacy platform string.
+        lookup(&navigator, "userAgentData")
+            .and_then(|data| lookup(&data, "platform"))
+            .or_else(|| lookup(&navigator, "platform"))
+            .and_then(|platform| platform.as_string())
+    });
+
+    match platform {
+        Some(platform) if platform.starts_with("Win") => Newline::Crlf,
+        _ => Newline::Lf,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{native, set_native_newline, Newline};
+    use crate::{Blob, BlobOptions, LineEndings};
+
+    #[test]
+    fn detect() {
+        #[cfg(target_os = "windows")]
+        assert_eq!(native(), Newline::Crlf);
+
+        #[cfg(not(target_os = "windows"))]
+        assert_eq!(native(), Newline::Lf);
+    }
+
+    #[tokio::test]
+    async fn set_native_newline_override() {
+        let opts = Some(BlobOptions::new(LineEndings::Native, None));
+
+        set_native_newline(Some(Newline::Crlf));
+        let crlf = Blob::from_strings(vec!["a\nb\rc\r\n"], opts.clone());
+
+        set_native_newline(Some(Newline::Lf));
+        let lf = Blob::from_strings(vec!["a\nb\rc\r\n"], opts);
+
+        set_native_newline(None);
+
+        assert_eq!(crlf.text().await.unwrap(), "a\r\nb\r\nc\r\n");
+        assert_eq!(lf.text().await.unwrap(), "a\nb\nc\n");
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32", feature = "js"))]
+mod wasm_tests {
+    use super::{native, set_native_newline, Newline};
+
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    fn detect_navigator() {
+        // The test runner executes in Node, which may not expose a navigator
+        // with a Windows platform. Either way, detection must not panic.
+        let detected = native();
+        assert!(matches!(detected, Newline::Lf | Newline::Crlf));
+
+        set_native_newline(Some(Newline::Crlf));
+        assert_eq!(native(), Newline::Crlf);
+
+        set_native_newline(None);
+        assert_eq!(native(), detected);
+    }
+}
@@ -0,0 +1,154 @@
+use std::ops::Deref;
+
+use js_sys::{Array, Function, Object, Reflect};
+use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::{JsCast, JsValue, UnwrapThrowExt};
+use web_sys::Url;
+
+use crate::Blob;
+
+#[wasm_bindgen]
+extern "C" {
+    // The binding in web-sys only accepts a web_sys::Blob, which can not be
+    // used here. See to_js_blob.
+    #[wasm_bindgen(js_namespace = URL, js_name = createObjectURL, catch)]
+    fn create_object_url_with_blob(blob: &JsValue) -> Result<String, JsValue>;
+}
+
+/// Copies the bytes in view of `blob` into a new JavaScript `Blob` with the
+/// same type.
+///
+/// This crate exports `JsBlob` as a class named `Blob`, and wasm-bindgen can
+/// not import a class with the same name. The global `Blob` constructor is
+/// looked up at runtime instead of through `web_sys::Blob`.
+///
+fn to_js_blob(blob: &Blob) -> JsValue {
+    let constructor: Function = Reflect::get(&js_sys::global(), &"Blob".into())
+        .unwrap_throw()
+        .dyn_into()
+        .unwrap_throw();
+
+    let opts = Object::new();
+    Reflect::set(&opts, &"type".into(), &blob.ty().into()).unwrap_throw();
+
+    // The bytes are copied into the JS heap in bulk before they are handed to
+    // the constructor.
+    let parts = Array::of1(&blob.bytes_sync());
+
+    Reflect::construct(&constructor, &Array::of2(&parts, &opts)).unwrap_throw()
+}
+
+/// Returns a `blob:` URL that refers to a copy of the bytes in `blob`.
+///
+/// The URL keeps the copy alive until it is passed to `revoke_object_url`.
+/// Prefer `ObjectUrl`, which revokes the URL when it is dropped.
+///
+pub fn create_object_url(blob: &Blob) -> String {
+    create_object_url_with_blob(&to_js_blob(blob)).unwrap_throw()
+}
+
+/// Revokes a URL that was returned by `create_object_url`, allowing the bytes
+/// it refers to to be released.
+///
+pub fn revoke_object_url(url: &str) {
+    Url::revoke_object_url(url).unwrap_throw();
+}
+
+/// A `blob:` URL that is revoked when it is dropped.
+///
+/// The URL dereferences to a `str`, so it can be used wherever a URL is
+/// expected, such as the `src` of an image or the `href` of a download link.
+/// It must outlive every use of the URL.
+///
+#[derive(Debug)]
+pub struct ObjectUrl {
+    url: String,
+}
+
+impl ObjectUrl {
+    /// Creates a `blob:` URL that refers to a copy of the bytes in `blob`.
+    ///
+    pub fn new(blob: &Blob) -> Self {
+        Self {
+            url: create_object_url(blob),
+        }
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.url
+    }
+}
+
+impl Deref for ObjectUrl {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        &self.url
+    }
+}
+
+impl Drop for ObjectUrl {
+    fn drop(&mut self) {
+        revoke_object_url(&self.url);
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use js_sys::{Promise, Uint8Array};
+    use wasm_bindgen::prelude::wasm_bindgen;
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+    use web_sys::Response;
+
+    use super::{create_object_url, revoke_object_url, ObjectUrl};
+    use crate::{Blob, BlobOptions, LineEndings};
+
+    #[wasm_bindgen]
+    extern "C" {
+        // The global fetch function, which is available in browsers and in
+        // Node.js alike.
+        fn fetch(url: &str) -> Promise;
+    }
+
+    async fn fetch_bytes(url: &str) -> Result<(Vec<u8>, String), wasm_bindgen::JsValue> {
+        let response: Response = JsFuture::from(fetch(url)).await?.unchecked_into();
+        let buffer = JsFuture::from(response.array_buffer()?).await?;
+        let ty = response.headers().get("content-type")?.unwrap_or_default();
+
+        Ok((Uint8Array::new(&buffer).to_vec(), ty))
+    }
+
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    async fn object_url() {
+        let opts = BlobOptions::new(LineEndings::Transparent, Some("text/plain".into()));
+        let blob = Blob::new(vec![&b"Hello, "[..], b"world!"], Some(opts));
+        let url = ObjectUrl::new(&blob.slice(7, None, Some("text/plain".into())));
+
+        assert!(url.starts_with("blob:"));
+        assert_eq!(
+            fetch_bytes(&url).await.unwrap(),
+            (b"world!".to_vec(), "text/plain".to_owned())
+        );
+
+        // The URL no longer resolves once the guard is dropped.
+        let url = {
+            let guard = ObjectUrl::new(&blob);
+            guard.as_str().to_owned()
+        };
+
+        assert!(fetch_bytes(&url).await.is_err());
+    }
+
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    async fn revoke() {
+        let url = create_object_url(&Blob::from("abc"));
+
+        assert_eq!(fetch_bytes(&url).await.unwrap().0, b"abc");
+
+        revoke_object_url(&url);
+        assert!(fetch_bytes(&url).await.is_err());
+    }
+}
@@ -0,0 +1,271 @@
+use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::{Blob, PartLen};
+
+/// A single part of the sequence that a Blob is constructed from.
+///
+/// This mirrors the `BlobPart` type of the Blob constructor in the WHATWG File
+/// API. A part can be a buffer of bytes, a string, or another Blob.
+///
+#[derive(Debug, Clone)]
+pub enum BlobPart {
+    /// Binary data that is copied into the blob without any conversion.
+    Bytes(Vec<u8>),
+
+    /// A string that is subject to the line ending conversion of the blob.
+    Str(String),
+
+    /// Another Blob. The bytes in view of the nested blob are shared with the
+    /// new blob rather than copied.
+    Blob(Blob),
+}
+
+/// The storage of the bytes of a part.
+///
+#[derive(Debug)]
+pub(crate) enum Buffer {
+    /// Bytes that are owned by the heap.
+    Heap(Vec<u8>),
+
+    /// The contents of a memory-mapped file.
+    #[cfg(feature = "mmap")]
+    Mmap(memmap2::Mmap),
+}
+
+impl Deref for Buffer {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        match self {
+            Self::Heap(bytes) => bytes,
+            #[cfg(feature = "mmap")]
+            Self::Mmap(map) => map,
+        }
+    }
+}
+
+/// A contiguous range of bytes in a buffer that may be shared with other
+/// blobs.
+///
+#[derive(Debug, Clone)]
+pub(crate) struct Part {
+    pub(crate) bytes: Arc<Buffer>,
+    start: usize,
+    end: usize,
+}
+
+impl Part {
+    /// Returns a new part that shares the buffer of self and contains the
+    /// bytes from `start` to `end`, relative to the start of self.
+    ///
+    #[inline]
+    pub(crate) fn slice(&self, start: usize, end: usize) -> Self {
+        Self {
+            bytes: Arc::clone(&self.bytes),
+            start: self.start + start,
+            end: self.start + end,
+        }
+    }
+
+    /// Moves the bytes from `start` to `end`, relative to the start of self,
+    /// out of the buffer of self without copying them to a new allocation.
+    ///
+    /// Returns `None` if the buffer is shared with another part or is not
+    /// owned by the heap. On success, self is left empty.
+    ///
+    // The heap is the only kind of buffer unless the mmap feature is enabled.
+    #[cfg_attr(not(feature = "mmap"), allow(irrefutable_let_patterns))]
+    pub(crate) fn take(&mut self, start: usize, end: usize) -> Option<Vec<u8>> {
+        let Buffer::Heap(bytes) = Arc::get_mut(&mut self.bytes)? else {
+            return None;
+        };
+
+        let mut bytes = std::mem::take(bytes);
+
+        // Discard the bytes outside of the range in place. This keeps the
+        // original allocation.
+        bytes.truncate(self.start + end);
+        bytes.drain(..self.start + start);
+
+        self.start = 0;
+        self.end = 0;
+
+        Some(bytes)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl Part {
+    /// Converts self into a `Bytes` that shares the buffer of self instead of
+    /// copying it.
+    ///
+    #[inline]
+    pub(crate) fn into_bytes(self) -> bytes::Bytes {
+        bytes::Bytes::from_owner(self)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl AsRef<[u8]> for Part {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl PartLen for Part {
+    #[inline]
+    fn part_len(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+impl Deref for Part {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        &self.bytes[self.start..self.end]
+    }
+}
+
+impl From<Vec<u8>> for Part {
+    #[inline]
+    fn from(bytes: Vec<u8>) -> Self {
+        Self {
+            end: bytes.len(),
+            start: 0,
+            bytes: Arc::new(Buffer::Heap(bytes)),
+        }
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl From<memmap2::Mmap> for Part {
+    #[inline]
+    fn from(map: memmap2::Mmap) -> Self {
+        Self {
+            end: map.len(),
+            start: 0,
+            bytes: Arc::new(Buffer::Mmap(map)),
+        }
+    }
+}
+
+impl From<Vec<u8>> for BlobPart {
+    #[inline]
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::Bytes(bytes)
+    }
+}
+
+impl From<&[u8]> for BlobPart {
+    #[inline]
+    fn from(bytes: &[u8]) -> Self {
+        Self::Bytes(bytes.to_vec())
+    }
+}
+
+impl From<String> for BlobPart {
+    #[inline]
+    fn from(text: String) -> Self {
+        Self::Str(text)
+    }
+}
+
+impl From<&str> for BlobPart {
+    #[inline]
+    fn from(text: &str) -> Self {
+        Self::Str(text.to_owned())
+    }
+}
+
+impl From<Blob> for BlobPart {
+    #[inline]
+    fn from(blob: Blob) -> Self {
+        Self::Blob(blob)
+    }
+}
+
+impl From<&Blob> for BlobPart {
+    #[inline]
+    fn from(blob: &Blob) -> Self {
+        Self::Blob(blob.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{set_native_newline, Blob, BlobOptions, BlobPart, LineEndings, Newline};
+
+    #[tokio::test]
+    async fn from_parts() {
+        let first = Blob::new(vec![&b"ABC"[..], b"DEF"], None);
+        let second = Blob::from("GHI");
+
+        let blob = Blob::from_parts(
+            vec![
+                BlobPart::from(&first),
+                BlobPart::from(second.slice(1, None, None)),
+                BlobPart::from("-JKL"),
+            ],
+            None,
+        );
+
+        assert_eq!(blob.size(), 12);
+        assert_eq!(blob.text().await.unwrap(), "ABCDEFHI-JKL");
+
+        // The nested blobs share their buffers with the new blob.
+        assert_eq!(blob.data.len(), 4);
+        assert!(Arc::ptr_eq(&blob.data[0].bytes, &first.data[0].bytes));
+        assert!(Arc::ptr_eq(&blob.data[1].bytes, &first.data[1].bytes));
+        assert!(Arc::ptr_eq(&blob.data[2].bytes, &second.data[0].bytes));
+    }
+
+    #[tokio::test]
+    async fn nested_view() {
+        let source = Blob::new(vec![&b"ABC"[..], b"DEF", b"GHI"], None);
+
+        // Only the bytes in view of the nested blob are included.
+        let blob = Blob::from_parts([source.slice(2, Some(7), None)], None);
+
+        assert_eq!(blob.data.len(), 3);
+        assert_eq!(blob.text().await.unwrap(), "CDEFG");
+
+        // Slices of the new blob are relative to the nested view.
+        assert_eq!(blob.slice(1, Some(3), None).text().await.unwrap(), "DE");
+
+        // Nesting a blob that was itself built from a nested blob.
+        let blob = Blob::from_parts([BlobPart::from(&blob), BlobPart::from(&blob)], None);
+
+        assert_eq!(blob.text().await.unwrap(), "CDEFGCDEFG");
+        assert!(Arc::ptr_eq(&blob.data[0].bytes, &source.data[0].bytes));
+    }
+
+    #[tokio::test]
+    async fn nested_endings() {
+        let inner = Blob::from("one\r\ntwo\r\nthree").slice(5, None, None);
+        let opts = Some(BlobOptions::new(LineEndings::Native, None));
+
+        set_native_newline(Some(Newline::Lf));
+
+        // Only the string parts of the outer blob are converted. The bytes of
+        // a nested blob are copied as is, like binary parts.
+        let blob = Blob::from_parts(
+            vec![
+                BlobPart::from("<\r\n"),
+                BlobPart::from(inner),
+                BlobPart::from(&b"\r\n>"[..]),
+            ],
+            opts,
+        );
+
+        set_native_newline(None);
+
+        assert_eq!(blob.text().await.unwrap(), "<\ntwo\r\nthree\r\n>");
+    }
+}
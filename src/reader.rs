@@ -0,0 +1,450 @@
+use std::io::{self, BufRead, Read, Seek, SeekFrom, Write};
+
+use crate::{Blob, BlobOptions, Cursor};
+
+/// The maximum number of bytes that are read into each part of a blob that is
+/// collected from a reader.
+pub(crate) const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+/// The number of bytes that an asynchronous reader may read before it yields
+/// to the executor.
+#[cfg(any(feature = "futures-io", feature = "tokio"))]
+pub(crate) const YIELD_BUDGET: usize = 1 << 20;
+
+/// A reader over the bytes contained in a Blob.
+///
+/// Bytes are copied directly from the parts of the blob into the buffer
+/// provided by the caller. The blob is never coalesced into a single
+/// contiguous buffer.
+///
+/// The reader also implements `BufRead` without buffering of its own. The
+/// buffer returned by `fill_buf` is the rest of the current part.
+///
+#[derive(Debug)]
+pub struct BlobReader<'a> {
+    blob: &'a Blob,
+
+    /// The position of the next part to read within the blob's view.
+    cursor: Cursor,
+
+    /// The bytes of the current part that have not been read yet.
+    chunk: &'a [u8],
+
+    /// The position of the next byte to read, relative to the start of the
+    /// blob's view. This may be past the end of the view after a seek.
+    pos: u64,
+}
+
+impl<'a> BlobReader<'a> {
+    pub(crate) fn new(blob: &'a Blob) -> Self {
+        Self {
+            blob,
+            cursor: Cursor::new(blob.range()),
+            chunk: &[],
+            pos: 0,
+        }
+    }
+
+    /// Returns the position of the next byte to read, relative to the start of
+    /// the blob's view.
+    ///
+    #[cfg(feature = "tokio")]
+    #[inline]
+    pub(crate) fn position(&self) -> u64 {
+        self.pos
+    }
+}
+
+impl Read for BlobReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        // Copy as many bytes as possible from the current chunk. Reads never
+        // span multiple parts. The caller will call read again for the rest.
+        let chunk = self.fill_buf()?;
+        let len = buf.len().min(chunk.len());
+
+        buf[..len].copy_from_slice(&chunk[..len]);
+        self.consume(len);
+
+        Ok(len)
+    }
+}
+
+impl BufRead for BlobReader<'_> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        // If the current chunk has been exhausted, advance to the next part in
+        // the view. Chunks are never empty, so an empty buffer is only ever
+        // returned at EOF.
+        if self.chunk.is_empty() {
+            if let Some(chunk) = self.cursor.next_chunk(&self.blob.data) {
+                self.chunk = chunk;
+            }
+        }
+
+        Ok(self.chunk)
+    }
+
+    fn consume(&mut self, amt: usize) {
+        let amt = amt.min(self.chunk.len());
+
+        self.chunk = &self.chunk[amt..];
+        self.pos += amt as u64;
+    }
+}
+
+impl Seek for BlobReader<'_> {
+    fn seek(&mut self, style: SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match style {
+            SeekFrom::Start(pos) => (pos, 0),
+            SeekFrom::End(offset) => (self.blob.size() as u64, offset),
+            SeekFrom::Current(offset) => (self.pos, offset),
+        };
+
+        let pos = base.checked_add_signed(offset).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+
+        // Start a new cursor at the absolute position of the next byte to read.
+        // A position past the end of the view results in a cursor that is
+        // already done, so subsequent reads return EOF.
+        let (from, to) = self.blob.range();
+        let start = usize::try_from(pos).map_or(to, |pos| from.saturating_add(pos).min(to));
+
+        self.cursor = Cursor::new((start, to));
+        self.chunk = &[];
+        self.pos = pos;
+
+        Ok(pos)
+    }
+}
+
+impl Blob {
+    /// Reads `reader` to the end into a new Blob that contains a single part.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `reader` fails.
+    ///
+    pub fn from_reader<R: Read>(mut reader: R, opts: Option<BlobOptions>) -> io::Result<Self> {
+        let mut bytes = Vec::new();
+
+        reader.read_to_end(&mut bytes)?;
+        Ok(Self::new([bytes], opts))
+    }
+
+    /// Reads `reader` to the end into a new Blob that stores the bytes in
+    /// parts of at most 64KB.
+    ///
+    /// Unlike `from_reader`, the bytes are never copied into a single
+    /// contiguous buffer, so reading a large file does not require one large
+    /// allocation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `reader` fails.
+    ///
+    pub fn from_reader_chunked<R: Read>(
+        mut reader: R,
+        opts: Option<BlobOptions>,
+    ) -> io::Result<Self> {
+        let mut parts = Vec::new();
+
+        loop {
+            // Fill each part before starting the next one, so small reads do
+            // not produce small parts.
+            let mut part = Vec::with_capacity(READ_CHUNK_SIZE);
+            let len = reader
+                .by_ref()
+                .take(READ_CHUNK_SIZE as u64)
+                .read_to_end(&mut part)?;
+
+            if len > 0 {
+                parts.push(part);
+            }
+
+            if len < READ_CHUNK_SIZE {
+                break;
+            }
+        }
+
+        Ok(Self::new(parts, opts))
+    }
+
+    /// Writes the bytes contained in self to `writer` and returns the number
+    /// of bytes written.
+    ///
+    /// Each part in view is written directly rather than coalesced. Partial
+    /// writes are retried and writes that fail with `ErrorKind::Interrupted`
+    /// are repeated.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error produced by `writer` other than `Interrupted`.
+    ///
+    pub fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<u64> {
+        let mut written = 0;
+
+        for chunk in self.chunks() {
+            writer.write_all(chunk)?;
+            written += chunk.len() as u64;
+        }
+
+        Ok(written)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, BufRead, Read, Seek, SeekFrom, Write};
+
+    use super::READ_CHUNK_SIZE;
+    use crate::Blob;
+
+    #[test]
+    fn read_to_end() {
+        let blob = Blob::new(vec![&b"ABC"[..], b"", b"DEFG", b"HI"], None);
+        let mut output = Vec::new();
+
+        io::copy(&mut blob.reader(), &mut output).unwrap();
+
+        assert_eq!(output, b"ABCDEFGHI");
+    }
+
+    #[test]
+    fn read_across_parts() {
+        let blob = Blob::new(vec![&b"ABC"[..], b"DEFG", b"HI"], None);
+        let mut reader = blob.reader();
+        let mut buf = [0; 2];
+
+        // Reads stop at the end of each part.
+        let mut reads = Vec::new();
+
+        loop {
+            match reader.read(&mut buf).unwrap() {
+                0 => break,
+                n => reads.push(buf[..n].to_vec()),
+            }
+        }
+
+        assert_eq!(reads, [&b"AB"[..], b"C", b"DE", b"FG", b"HI"]);
+    }
+
+    #[test]
+    fn read_slice() {
+        let blob = Blob::new(vec![&b"ABC"[..], b"DEFG", b"HI"], None);
+        let slice = blob.slice(2, Some(8), None);
+        let mut output = String::new();
+
+        slice.reader().read_to_string(&mut output).unwrap();
+
+        assert_eq!(output, "CDEFGH");
+    }
+
+    #[test]
+    fn read_odd_chunks() {
+        let parts: Vec<_> = (0..20u8).map(|n| vec![n; usize::from(n % 5)]).collect();
+        let blob = Blob::new(parts, None).slice(3, Some(-3), None);
+        let mut reader = blob.reader();
+        let mut output = Vec::new();
+        let mut buf = [0; 7];
+
+        loop {
+            match reader.read(&mut buf).unwrap() {
+                0 => break,
+                n => output.extend_from_slice(&buf[..n]),
+            }
+        }
+
+        assert_eq!(output, blob.to_vec());
+        assert_eq!(reader.stream_position().unwrap(), blob.size() as u64);
+    }
+
+    #[test]
+    fn seek() {
+        let blob = Blob::new(vec![&b"ABC"[..], b"DEFG", b"HI"], None);
+        let mut reader = blob.reader();
+        let mut buf = [0; 3];
+
+        assert_eq!(reader.seek(SeekFrom::Start(4)).unwrap(), 4);
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"EFG");
+
+        assert_eq!(reader.seek(SeekFrom::Current(-5)).unwrap(), 2);
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"CDE");
+
+        assert_eq!(reader.seek(SeekFrom::End(-2)).unwrap(), 7);
+        let mut rest = String::new();
+        reader.read_to_string(&mut rest).unwrap();
+        assert_eq!(rest, "HI");
+
+        // Seeking past the end is allowed and reads return EOF.
+        assert_eq!(reader.seek(SeekFrom::End(10)).unwrap(), 19);
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+
+        // Seeking before the start is an error and leaves the position as is.
+        let error = reader.seek(SeekFrom::Current(-20)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(reader.stream_position().unwrap(), 19);
+    }
+
+    #[test]
+    fn seek_slice() {
+        let blob = Blob::new(vec![&b"ABC"[..], b"DEFG", b"HI"], None);
+        let slice = blob.slice(2, Some(8), None);
+        let mut reader = slice.reader();
+        let mut output = String::new();
+
+        // Positions are relative to the view of the slice.
+        reader.seek(SeekFrom::Start(2)).unwrap();
+        reader.read_to_string(&mut output).unwrap();
+        assert_eq!(output, "EFGH");
+
+        reader.seek(SeekFrom::End(-1)).unwrap();
+        output.clear();
+        reader.read_to_string(&mut output).unwrap();
+        assert_eq!(output, "H");
+    }
+
+    #[test]
+    fn fill_buf_part_boundary() {
+        let blob = Blob::new(vec![&b"ABC"[..], b"", b"DEFG"], None);
+        let mut reader = blob.reader();
+
+        assert_eq!(reader.fill_buf().unwrap(), b"ABC");
+        reader.consume(3);
+
+        // The empty part is skipped and the next part is exposed as a whole.
+        assert_eq!(reader.fill_buf().unwrap(), b"DEFG");
+        reader.consume(1);
+        assert_eq!(reader.fill_buf().unwrap(), b"EFG");
+        reader.consume(3);
+
+        assert_eq!(reader.fill_buf().unwrap(), b"");
+        assert_eq!(reader.stream_position().unwrap(), 7);
+    }
+
+    #[test]
+    fn fill_buf_slice() {
+        let blob = Blob::new(vec![&b"ABCD"[..], b"EFGH", b"IJKL"], None);
+        let slice = blob.slice(2, Some(-2), None);
+        let mut reader = slice.reader();
+
+        // The view starts and ends in the middle of a part.
+        assert_eq!(reader.fill_buf().unwrap(), b"CD");
+        reader.consume(2);
+        assert_eq!(reader.fill_buf().unwrap(), b"EFGH");
+        reader.consume(4);
+        assert_eq!(reader.fill_buf().unwrap(), b"IJ");
+        reader.consume(2);
+        assert_eq!(reader.fill_buf().unwrap(), b"");
+
+        // Seeking into the middle of a part exposes the rest of that part.
+        reader.seek(SeekFrom::Start(3)).unwrap();
+        assert_eq!(reader.fill_buf().unwrap(), b"FGH");
+    }
+
+    #[test]
+    fn read_line_across_parts() {
+        let blob = Blob::new(vec![&b"one\nt"[..], b"w", b"o\nthree"], None);
+        let lines: Vec<_> = blob.reader().lines().map(Result::unwrap).collect();
+
+        assert_eq!(lines, ["one", "two", "three"]);
+
+        let mut reader = blob.reader();
+        let mut line = Vec::new();
+
+        reader.read_until(b'\n', &mut line).unwrap();
+        line.clear();
+        reader.read_until(b'\n', &mut line).unwrap();
+        assert_eq!(line, b"two\n");
+    }
+
+    #[tokio::test]
+    async fn from_reader() {
+        let text = "one\ntwo\nthree\n".repeat(10_000);
+        let blob = Blob::from_reader(io::Cursor::new(&text), None).unwrap();
+
+        assert_eq!(blob.data.len(), 1);
+        assert_eq!(blob.text().await.unwrap(), text);
+
+        let blob = Blob::from_reader_chunked(io::Cursor::new(&text), None).unwrap();
+
+        assert_eq!(blob.data.len(), text.len().div_ceil(READ_CHUNK_SIZE));
+        assert!(blob.data.iter().all(|part| part.len() <= READ_CHUNK_SIZE));
+        assert_eq!(blob.text().await.unwrap(), text);
+
+        // A reader that ends on a part boundary does not produce an empty part.
+        let bytes = vec![b'x'; READ_CHUNK_SIZE];
+        let blob = Blob::from_reader_chunked(&bytes[..], None).unwrap();
+
+        assert_eq!(blob.data.len(), 1);
+        assert_eq!(
+            Blob::from_reader_chunked(io::empty(), None).unwrap(),
+            Blob::empty()
+        );
+    }
+
+    #[test]
+    fn from_reader_error() {
+        // A reader that fails after producing some bytes.
+        let reader = || (&b"ABC"[..]).chain(FailingReader);
+
+        assert!(Blob::from_reader(reader(), None).is_err());
+        assert!(Blob::from_reader_chunked(reader(), None).is_err());
+    }
+
+    #[test]
+    fn write_to() {
+        let blob = Blob::new(vec![&b"ABC"[..], b"", b"DEFG", b"HI"], None);
+        let slice = blob.slice(1, Some(-1), None);
+        let mut output = Vec::new();
+
+        assert_eq!(slice.write_to(&mut output).unwrap(), 7);
+        assert_eq!(output, b"BCDEFGH");
+
+        // Partial and interrupted writes are retried.
+        let mut writer = StutteringWriter::default();
+
+        assert_eq!(blob.write_to(&mut writer).unwrap(), 9);
+        assert_eq!(writer.output, b"ABCDEFGHI");
+        assert_eq!(writer.calls, 7);
+    }
+
+    /// A writer that accepts at most 2 bytes per call and fails every third
+    /// call with `ErrorKind::Interrupted`.
+    #[derive(Default)]
+    struct StutteringWriter {
+        output: Vec<u8>,
+        calls: usize,
+    }
+
+    impl Write for StutteringWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.calls += 1;
+
+            if self.calls.is_multiple_of(3) {
+                return Err(io::ErrorKind::Interrupted.into());
+            }
+
+            let len = buf.len().min(2);
+
+            self.output.extend_from_slice(&buf[..len]);
+            Ok(len)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    struct FailingReader;
+
+    impl Read for FailingReader {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("connection reset"))
+        }
+    }
+}
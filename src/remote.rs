@@ -0,0 +1,244 @@
+use std::cell::OnceCell;
+use std::io;
+
+use reqwest::blocking::{Client, Response};
+use reqwest::header::{CONTENT_LENGTH, CONTENT_TYPE, RANGE};
+use reqwest::StatusCode;
+
+use crate::{Blob, BlobDataSource, BlobOptions, LazyBlob, LineEndings};
+
+/// A `BlobDataSource` that reads a remote resource with HTTP range requests.
+///
+struct RangeSource {
+    client: Client,
+    url: String,
+    len: u64,
+
+    /// The entire body of the resource, if the server ignored a range request
+    /// and sent it in full.
+    full: OnceCell<Vec<u8>>,
+}
+
+impl RangeSource {
+    /// Copies the bytes of `body` starting at `offset` into `buf`.
+    ///
+    fn copy(body: &[u8], offset: u64, buf: &mut [u8]) -> usize {
+        let body =
+            usize::try_from(offset).map_or(&[][..], |offset| body.get(offset..).unwrap_or(&[]));
+        let len = buf.len().min(body.len());
+
+        buf[..len].copy_from_slice(&body[..len]);
+        len
+    }
+}
+
+impl BlobDataSource for RangeSource {
+    fn len(&self) -> u64 {
+        self.len
+    }
+
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> io::Result<usize> {
+        if let Some(body) = self.full.get() {
+            return Ok(Self::copy(body, offset, buf));
+        }
+
+        if buf.is_empty() || offset >= self.len {
+            return Ok(0);
+        }
+
+        let last = offset + buf.len() as u64 - 1;
+        let response = self
+            .client
+            .get(&self.url)
+            .header(RANGE, format!("bytes={offset}-{last}"))
+            .send()
+            .and_then(Response::error_for_status)
+            .map_err(io::Error::other)?;
+
+        let status = response.status();
+        let body = response.bytes().map_err(io::Error::other)?;
+
+        match status {
+            StatusCode::PARTIAL_CONTENT => Ok(Self::copy(&body, 0, buf)),
+
+            // The server does not support range requests. Keep the body so
+            // that every other part is read from it instead of downloading the
+            // resource again.
+            _ => Ok(Self::copy(
+                self.full.get_or_init(|| body.into()),
+                offset,
+                buf,
+            )),
+        }
+    }
+}
+
+impl Blob {
+    /// Returns a LazyBlob that reads the resource at `url` on demand with HTTP
+    /// range requests.
+    ///
+    /// A HEAD request is sent up front to learn the size and type of the blob
+    /// from the Content-Length and Content-Type headers. Each part of the blob
+    /// is then requested with a `Range` header the first time it is loaded,
+    /// so loading a slice only transfers the parts that overlap it. See
+    /// `from_source` for details.
+    ///
+    /// If the server ignores a range request, the resource is downloaded in
+    /// full once and every part is read from that response.
+    ///
+    /// The parts are read with `LazyBlob::load`, so this uses the blocking
+    /// client of `reqwest`. It must not be called from an async context.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HEAD request fails or the response does not
+    /// have a valid Content-Length.
+    ///
+    pub fn from_url(url: &str, client: &Client) -> io::Result<LazyBlob> {
+        let response = client
+            .head(url)
+            .send()
+            .and_then(Response::error_for_status)
+            .map_err(io::Error::other)?;
+
+        let headers = response.headers();
+        let len = headers
+            .get(CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok()?.parse().ok())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "response to HEAD does not have a valid Content-Length",
+                )
+            })?;
+
+        let ty = headers
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(Box::from);
+
+        let source = RangeSource {
+            client: client.clone(),
+            url: url.to_owned(),
+            len,
+            full: OnceCell::new(),
+        };
+
+        Ok(Self::from_source(
+            source,
+            Some(BlobOptions::new(LineEndings::Transparent, ty)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::blocking::Client;
+    use tokio::runtime::{Builder, Runtime};
+    use wiremock::matchers::{header, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::Blob;
+
+    fn runtime() -> Runtime {
+        Builder::new_current_thread().enable_all().build().unwrap()
+    }
+
+    /// 200KB of bytes, where each byte is its offset modulo 251.
+    fn body() -> Vec<u8> {
+        (0..200_000u32).map(|offset| (offset % 251) as u8).collect()
+    }
+
+    /// Starts a server that responds to HEAD with the length and type of
+    /// `body`. The server runs on a thread of its own, so the mocks can be
+    /// used by the blocking client outside of an async context.
+    fn server(runtime: &Runtime) -> MockServer {
+        runtime.block_on(async {
+            let server = MockServer::start().await;
+
+            Mock::given(method("HEAD"))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .insert_header("content-length", body().len().to_string())
+                        .insert_header("content-type", "application/octet-stream"),
+                )
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            server
+        })
+    }
+
+    #[test]
+    fn from_url() {
+        let runtime = runtime();
+        let server = server(&runtime);
+
+        // Only the part that overlaps the slice is requested.
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .and(header("range", "bytes=65536-131071"))
+                .respond_with(ResponseTemplate::new(206).set_body_bytes(&body()[65_536..131_072]))
+                .expect(1)
+                .mount(&server),
+        );
+
+        let blob = Blob::from_url(&format!("{}/asset", server.uri()), &Client::new()).unwrap();
+
+        assert_eq!(blob.size(), 200_000);
+        assert_eq!(blob.ty(), "application/octet-stream");
+
+        // Loading the slice twice only sends one request.
+        let slice = blob.slice(70_000, Some(70_010), None);
+
+        assert_eq!(slice.try_to_vec().unwrap(), body()[70_000..70_010]);
+        assert_eq!(slice.try_to_vec().unwrap(), body()[70_000..70_010]);
+
+        runtime.block_on(server.verify());
+    }
+
+    #[test]
+    fn from_url_ignored_range() {
+        let runtime = runtime();
+        let server = server(&runtime);
+
+        // A server that ignores the Range header is only asked for the body
+        // once.
+        runtime.block_on(
+            Mock::given(method("GET"))
+                .respond_with(ResponseTemplate::new(200).set_body_bytes(body()))
+                .expect(1)
+                .mount(&server),
+        );
+
+        let blob = Blob::from_url(&format!("{}/asset", server.uri()), &Client::new()).unwrap();
+
+        assert_eq!(blob.try_to_vec().unwrap(), body());
+        runtime.block_on(server.verify());
+    }
+
+    #[test]
+    fn from_url_error() {
+        let runtime = runtime();
+        let server = runtime.block_on(MockServer::start());
+        let url = format!("{}/asset", server.uri());
+
+        // The resource does not exist.
+        let error = Blob::from_url(&url, &Client::new()).unwrap_err();
+        assert!(error.to_string().contains("404"), "{error}");
+
+        // A part that can not be read is reported when it is loaded.
+        runtime.block_on(
+            Mock::given(method("HEAD"))
+                .respond_with(ResponseTemplate::new(200).insert_header("content-length", "10"))
+                .mount(&server),
+        );
+
+        let blob = Blob::from_url(&url, &Client::new()).unwrap();
+
+        assert_eq!(blob.size(), 10);
+        assert_eq!(blob.ty(), "");
+        assert!(blob.try_to_vec().unwrap_err().to_string().contains("404"));
+    }
+}
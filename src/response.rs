@@ -0,0 +1,179 @@
+use axum::body::Body;
+use axum::http::header::{CONTENT_DISPOSITION, CONTENT_LENGTH, CONTENT_TYPE};
+use axum::http::HeaderValue;
+use axum::response::{IntoResponse, Response};
+
+use crate::{Blob, File};
+
+/// Responds with the bytes contained in a Blob.
+///
+/// The Content-Type of the response is the type of the blob, or
+/// `application/octet-stream` if it is unknown. The body is streamed one part
+/// at a time rather than coalesced, and each part shares its buffer with the
+/// blob instead of being copied.
+///
+impl IntoResponse for Blob {
+    fn into_response(self) -> Response {
+        let ty = match self.ty() {
+            "" => HeaderValue::from_static("application/octet-stream"),
+            // The type of a blob is always printable ASCII.
+            ty => HeaderValue::from_str(ty).unwrap(),
+        };
+
+        let mut response = Response::new(Body::from_stream(self.body_stream()));
+        let headers = response.headers_mut();
+
+        headers.insert(CONTENT_TYPE, ty);
+        headers.insert(CONTENT_LENGTH, HeaderValue::from(self.size()));
+
+        response
+    }
+}
+
+/// Responds with the bytes contained in a File as an attachment.
+///
+/// This is the same as the response of a Blob with an additional
+/// Content-Disposition header that contains the name of the file.
+///
+impl IntoResponse for File {
+    fn into_response(self) -> Response {
+        let disposition = content_disposition(self.name());
+        let mut response = self.into_blob().into_response();
+
+        response
+            .headers_mut()
+            .insert(CONTENT_DISPOSITION, disposition);
+
+        response
+    }
+}
+
+/// Returns the value of a Content-Disposition header for an attachment named
+/// `name`, as described in RFC 6266.
+///
+/// Names that are not printable ASCII or contain a quote or a backslash are
+/// additionally encoded with the `filename*` parameter of RFC 8187. The plain
+/// `filename` parameter contains a lossy fallback for older clients.
+///
+fn content_disposition(name: &str) -> HeaderValue {
+    let plain = |ch: char| (' '..='~').contains(&ch) && ch != '"' && ch != '\\';
+
+    let fallback: String = name
+        .chars()
+        .map(|ch| if plain(ch) { ch } else { '_' })
+        .collect();
+
+    let mut value = format!("attachment; filename=\"{fallback}\"");
+
+    if !name.chars().all(plain) {
+        value.push_str("; filename*=UTF-8''");
+
+        for byte in name.bytes() {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                    value.push(char::from(byte));
+                }
+                _ => value.push_str(&format!("%{byte:02X}")),
+            }
+        }
+    }
+
+    // Every byte of the value is printable ASCII.
+    HeaderValue::from_str(&value).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::{to_bytes, Body};
+    use axum::http::header::{CONTENT_DISPOSITION, CONTENT_LENGTH, CONTENT_TYPE};
+    use axum::http::{Request, StatusCode};
+    use axum::response::{IntoResponse, Response};
+    use axum::routing::get;
+    use axum::Router;
+    use futures::StreamExt;
+    use tower::ServiceExt;
+
+    use super::content_disposition;
+    use crate::{Blob, BlobOptions, File, LineEndings};
+
+    async fn oneshot(router: Router) -> Response {
+        let request = Request::builder().uri("/").body(Body::empty()).unwrap();
+        router.oneshot(request).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn blob() {
+        let router = Router::new().route(
+            "/",
+            get(|| async {
+                let opts = BlobOptions::new(LineEndings::Transparent, Some("text/plain".into()));
+                Blob::from_parts(vec!["hello, ", "world"], Some(opts))
+                    .slice_with_parent_type(2, None)
+            }),
+        );
+
+        let response = oneshot(router).await;
+        let headers = response.headers();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(headers[CONTENT_TYPE], "text/plain");
+        assert_eq!(headers[CONTENT_LENGTH], "10");
+        assert!(headers.get(CONTENT_DISPOSITION).is_none());
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(body, "llo, world");
+    }
+
+    #[tokio::test]
+    async fn blob_parts() {
+        let blob = Blob::new(vec![&b"ABCD"[..], b"EFGH"], None).slice(1, None, None);
+        let body = blob.clone().into_response().into_body();
+        let chunks: Vec<_> = body.into_data_stream().map(Result::unwrap).collect().await;
+
+        // Each part is sent as a separate chunk that shares its buffer.
+        assert_eq!(chunks, [&b"BCD"[..], b"EFGH"]);
+        assert_eq!(chunks[0].as_ptr(), blob.data[0][1..].as_ptr());
+        assert_eq!(chunks[1].as_ptr(), blob.data[1].as_ptr());
+    }
+
+    #[tokio::test]
+    async fn blob_untyped() {
+        let router = Router::new().route(
+            "/",
+            get(|| async { Blob::new(vec![&b"\x00\x01"[..]], None) }),
+        );
+        let response = oneshot(router).await;
+
+        assert_eq!(response.headers()[CONTENT_TYPE], "application/octet-stream");
+        assert_eq!(response.headers()[CONTENT_LENGTH], "2");
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(body, &b"\x00\x01"[..]);
+    }
+
+    #[tokio::test]
+    async fn file() {
+        let router = Router::new().route(
+            "/",
+            get(|| async { File::new(vec!["a,b\n", "1,2\n"], "report.csv", None) }),
+        );
+
+        let response = oneshot(router).await;
+
+        assert_eq!(
+            response.headers()[CONTENT_DISPOSITION],
+            "attachment; filename=\"report.csv\""
+        );
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(body, "a,b\n1,2\n");
+    }
+
+    #[test]
+    fn disposition_escaped() {
+        assert_eq!(
+            content_disposition("caf\u{e9} \"menu\".pdf"),
+            "attachment; filename=\"caf_ _menu_.pdf\"; filename*=UTF-8''caf%C3%A9%20%22menu%22.pdf"
+        );
+    }
+}
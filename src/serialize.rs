@@ -0,0 +1,182 @@
+use std::borrow::Cow;
+use std::fmt::{self, Formatter};
+
+use serde::de::{self, Deserializer, SeqAccess, Unexpected, Visitor};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+
+use crate::data_url::{decode_base64, encode_base64};
+use crate::{Blob, BlobOptions, LineEndings};
+
+/// Serializes a Blob as the bytes in view of the blob along with its options.
+///
+/// Human-readable formats, such as JSON, encode the bytes as a base64 string.
+/// Other formats store the bytes as is.
+///
+impl Serialize for Blob {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Avoid copying the bytes if they are already contiguous.
+        let bytes = match self.as_contiguous() {
+            Some(bytes) => Cow::Borrowed(bytes),
+            None => Cow::Owned(self.coalesce()),
+        };
+
+        let mut state = serializer.serialize_struct("Blob", 4)?;
+
+        state.serialize_field("type", self.ty())?;
+        state.serialize_field("endings", &self.opts.endings)?;
+        state.serialize_field("strip_bom", &self.opts.strip_bom)?;
+        state.serialize_field("bytes", &Bytes(&bytes))?;
+        state.end()
+    }
+}
+
+/// Deserializes a Blob that contains a single part.
+///
+/// The bytes are stored as is. Line endings were converted when the original
+/// blob was constructed, so they are not converted again.
+///
+impl<'de> Deserialize<'de> for Blob {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = Repr::deserialize(deserializer)?;
+        let opts =
+            BlobOptions::new(repr.endings, Some(repr.ty.into())).with_strip_bom(repr.strip_bom);
+
+        Ok(Blob::new([repr.bytes.0], Some(opts)))
+    }
+}
+
+/// The owned representation of a serialized Blob.
+///
+#[derive(Deserialize)]
+#[serde(rename = "Blob")]
+struct Repr {
+    #[serde(rename = "type", default)]
+    ty: String,
+
+    #[serde(default)]
+    endings: LineEndings,
+
+    #[serde(default)]
+    strip_bom: bool,
+
+    bytes: ByteBuf,
+}
+
+/// A borrowed byte slice that serializes as base64 in human-readable formats.
+///
+struct Bytes<'a>(&'a [u8]);
+
+impl Serialize for Bytes<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return serializer.serialize_bytes(self.0);
+        }
+
+        let mut encoded = String::with_capacity(self.0.len().div_ceil(3) * 4);
+
+        encode_base64(self.0, &mut encoded);
+        serializer.serialize_str(&encoded)
+    }
+}
+
+/// An owned byte buffer that deserializes from the output of `Bytes`.
+///
+struct ByteBuf(Vec<u8>);
+
+impl<'de> Deserialize<'de> for ByteBuf {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(ByteBufVisitor)
+        } else {
+            deserializer.deserialize_byte_buf(ByteBufVisitor)
+        }
+    }
+}
+
+struct ByteBufVisitor;
+
+impl<'de> Visitor<'de> for ByteBufVisitor {
+    type Value = ByteBuf;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("a base64 string or a byte array")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<ByteBuf, E> {
+        match decode_base64(value) {
+            Some(bytes) => Ok(ByteBuf(bytes)),
+            None => Err(E::invalid_value(Unexpected::Str(value), &self)),
+        }
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<ByteBuf, E> {
+        Ok(ByteBuf(value.to_vec()))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, value: Vec<u8>) -> Result<ByteBuf, E> {
+        Ok(ByteBuf(value))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<ByteBuf, A::Error> {
+        // Don't trust the size hint of untrusted input with a large allocation.
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+
+        Ok(ByteBuf(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Blob, BlobOptions, LineEndings};
+
+    fn sample() -> Blob {
+        let opts = BlobOptions::new(LineEndings::Crlf, Some("text/plain".into()));
+        let blob = Blob::from_strings(vec!["one\n", "two\n", "three"], Some(opts));
+
+        // "two\r\nthree" spans two parts.
+        blob.slice_with_parent_type(5, None)
+    }
+
+    #[tokio::test]
+    async fn json() {
+        let blob = sample();
+        let json = serde_json::to_string(&blob).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"type":"text/plain","endings":"crlf","strip_bom":false,"bytes":"dHdvDQp0aHJlZQ=="}"#
+        );
+
+        let decoded: Blob = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded, blob);
+        assert_eq!(decoded.data.len(), 1);
+        assert_eq!(decoded.opts.endings, LineEndings::Crlf);
+        assert_eq!(decoded.text().await.unwrap(), "two\r\nthree");
+
+        // The options are optional and invalid base64 is rejected.
+        let decoded: Blob = serde_json::from_str(r#"{"bytes":""}"#).unwrap();
+        assert_eq!(decoded, Blob::empty());
+        assert!(serde_json::from_str::<Blob>(r#"{"bytes":"not base64"}"#).is_err());
+    }
+
+    #[test]
+    fn bincode() {
+        let blob = sample();
+        let encoded = bincode::serialize(&blob).unwrap();
+        let decoded: Blob = bincode::deserialize(&encoded).unwrap();
+
+        assert_eq!(decoded, blob);
+        assert_eq!(decoded.opts.endings, LineEndings::Crlf);
+        assert_eq!(decoded.to_vec(), b"two\r\nthree");
+
+        let empty: Blob =
+            bincode::deserialize(&bincode::serialize(&Blob::empty()).unwrap()).unwrap();
+        assert_eq!(empty, Blob::empty());
+    }
+}
@@ -0,0 +1,197 @@
+use crate::Blob;
+
+/// The byte patterns that identify a MIME type, as described by the WHATWG
+/// MIME Sniffing Standard. A blob matches a pattern if its bytes start with
+/// the pattern.
+///
+const SIGNATURES: &[(&[u8], &str)] = &[
+    (b"\x89PNG\r\n\x1A\n", "image/png"),
+    (b"\xFF\xD8\xFF", "image/jpeg"),
+    (b"GIF87a", "image/gif"),
+    (b"GIF89a", "image/gif"),
+    (b"%PDF-", "application/pdf"),
+    (b"PK\x03\x04", "application/zip"),
+    (b"\x1F\x8B\x08", "application/gzip"),
+    // A byte order mark identifies text in UTF-16BE, UTF-16LE, or UTF-8.
+    (b"\xFE\xFF", "text/plain"),
+    (b"\xFF\xFE", "text/plain"),
+    (b"\xEF\xBB\xBF", "text/plain"),
+];
+
+/// The formats of a RIFF container, identified by the four bytes that follow
+/// the "RIFF" tag and the size of the container.
+///
+const RIFF_FORMATS: &[(&[u8], &str)] = &[(b"WEBP", "image/webp"), (b"WAVE", "audio/wav")];
+
+/// The number of bytes that are inspected to match a signature. This is the
+/// length of a RIFF header.
+const HEADER_LEN: usize = 12;
+
+/// The number of bytes that are inspected by the plain text heuristic, as
+/// defined by the WHATWG MIME Sniffing Standard.
+const TEXT_HEADER_LEN: usize = 1445;
+
+/// Returns true if `byte` is a control character that does not occur in text.
+///
+fn is_binary(byte: u8) -> bool {
+    matches!(byte, 0x00..=0x08 | 0x0B | 0x0E..=0x1A | 0x1C..=0x1F)
+}
+
+impl Blob {
+    /// Returns the MIME type identified by the first bytes of self, if they
+    /// match the signature of a common format.
+    ///
+    /// Only the first bytes in view are inspected and they are never
+    /// coalesced. The supported formats are PNG, JPEG, GIF, WebP, PDF, ZIP,
+    /// gzip, WAV, and MP4. Text that starts with a byte order mark, or whose
+    /// first 1445 bytes do not contain a control character that is unusual
+    /// in text, is identified as `text/plain`. The type of self is not taken
+    /// into account.
+    ///
+    pub fn sniff_type(&self) -> Option<&'static str> {
+        let mut header = [0; HEADER_LEN];
+        let mut len = 0;
+
+        for (slot, byte) in header.iter_mut().zip(self.iter()) {
+            *slot = byte;
+            len += 1;
+        }
+
+        let header = &header[..len];
+
+        if let Some((_, ty)) = SIGNATURES
+            .iter()
+            .find(|(signature, _)| header.starts_with(signature))
+        {
+            return Some(ty);
+        }
+
+        if header.starts_with(b"RIFF") && len == HEADER_LEN {
+            if let Some((_, ty)) = RIFF_FORMATS
+                .iter()
+                .find(|(format, _)| &header[8..] == *format)
+            {
+                return Some(ty);
+            }
+        }
+
+        // An ISO base media file starts with the size of its ftyp box.
+        if header.get(4..8) == Some(b"ftyp") {
+            return Some("video/mp4");
+        }
+
+        if len > 0 && !self.iter().take(TEXT_HEADER_LEN).any(is_binary) {
+            return Some("text/plain");
+        }
+
+        None
+    }
+
+    /// Returns a Blob that shares the bytes of self and has the type returned
+    /// by `sniff_type`.
+    ///
+    /// If the type can not be sniffed, the returned blob has the type of self.
+    ///
+    pub fn with_sniffed_type(&self) -> Self {
+        match self.sniff_type() {
+            Some(ty) => self.slice(0, None, Some(ty.to_owned())),
+            None => self.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Blob;
+
+    #[test]
+    fn sniff() {
+        let headers: [(&[u8], &str); 14] = [
+            (b"\x89PNG\r\n\x1A\n\x00\x00\x00\rIHDR", "image/png"),
+            (b"\xFF\xD8\xFF\xE0\x00\x10JFIF", "image/jpeg"),
+            (b"GIF87a\x01\x00", "image/gif"),
+            (b"GIF89a\x01\x00", "image/gif"),
+            (b"RIFF\x24\x00\x00\x00WEBPVP8 ", "image/webp"),
+            (b"%PDF-1.7\n", "application/pdf"),
+            (b"PK\x03\x04\x14\x00\x00\x00", "application/zip"),
+            (b"\x1F\x8B\x08\x00\x00\x00\x00\x00", "application/gzip"),
+            (b"RIFF\x24\x08\x00\x00WAVEfmt ", "audio/wav"),
+            (b"\x00\x00\x00\x18ftypmp42\x00\x00", "video/mp4"),
+            (b"\xFE\xFF\x00h\x00i", "text/plain"),
+            (b"\xFF\xFEh\x00i\x00", "text/plain"),
+            (b"\xEF\xBB\xBFhi", "text/plain"),
+            (b"plain text\r\n\twith a tab\x0C", "text/plain"),
+        ];
+
+        for (header, ty) in headers {
+            assert_eq!(Blob::from(header).sniff_type(), Some(ty), "{header:?}");
+
+            // The signature may span parts.
+            let blob = Blob::new(header.chunks(1), None);
+            assert_eq!(blob.sniff_type(), Some(ty), "{header:?}");
+
+            // A tiny first part is read through to the next.
+            let blob = Blob::new(vec![&header[..2], &header[2..]], None);
+            assert_eq!(blob.sniff_type(), Some(ty), "{header:?}");
+        }
+    }
+
+    #[test]
+    fn sniff_none() {
+        assert_eq!(Blob::from(&b"\x00\x01\x02"[..]).sniff_type(), None);
+        assert_eq!(Blob::empty().sniff_type(), None);
+
+        // Truncated headers that contain binary bytes are not recognized.
+        assert_eq!(Blob::from(&b"\x89PNG\r\n\x1A"[..]).sniff_type(), None);
+        assert_eq!(
+            Blob::from(&b"RIFF\x24\x00\x00\x00WEB"[..]).sniff_type(),
+            None
+        );
+        assert_eq!(Blob::from(&b"\x00\x00\x00\x18fty"[..]).sniff_type(), None);
+
+        // A truncated header without binary bytes is indistinguishable from
+        // text.
+        assert_eq!(Blob::from("%PD").sniff_type(), Some("text/plain"));
+
+        // A binary byte past the first 1445 bytes is not inspected.
+        let mut text = vec![b'a'; 1445];
+        text.push(0);
+        assert_eq!(Blob::from(text).sniff_type(), Some("text/plain"));
+
+        // Only the bytes in view are inspected.
+        let blob = Blob::from(&b"\x00\x01%PDF-1.7"[..]);
+        assert_eq!(blob.sniff_type(), None);
+        assert_eq!(
+            blob.slice(2, None, None).sniff_type(),
+            Some("application/pdf")
+        );
+    }
+
+    #[tokio::test]
+    async fn with_sniffed_type() {
+        let blob = Blob::new(vec![&b"\x89P"[..], b"NG\r\n\x1A\n\x00"], None);
+        let sniffed = blob.with_sniffed_type();
+
+        assert_eq!(sniffed.ty(), "image/png");
+        assert_eq!(sniffed, {
+            let opts =
+                crate::BlobOptions::new(crate::LineEndings::Transparent, Some("image/png".into()));
+            Blob::new(vec![blob.to_vec()], Some(opts))
+        });
+
+        // The type of a blob that can not be sniffed is kept.
+        let opts =
+            crate::BlobOptions::new(crate::LineEndings::Transparent, Some("font/woff".into()));
+        let blob = Blob::new(vec![&b"wOFF\x00\x01"[..]], Some(opts));
+
+        assert_eq!(blob.with_sniffed_type().ty(), "font/woff");
+        assert_eq!(
+            Blob::from(" hello ")
+                .with_sniffed_type()
+                .text()
+                .await
+                .unwrap(),
+            " hello "
+        );
+    }
+}
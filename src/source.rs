@@ -0,0 +1,662 @@
+use std::cell::OnceCell;
+use std::fmt::{self, Debug, Formatter};
+use std::future::{self, Future};
+use std::io::{self, BufRead, Read};
+use std::pin::Pin;
+use std::rc::Rc;
+
+use crate::part::Part;
+use crate::reader::READ_CHUNK_SIZE;
+use crate::{resolve_range, Blob, BlobOptions, Cursor, PartLen};
+
+/// The `Future` returned by `BlobDataSource::read_at_async`.
+///
+pub type ReadAtFuture<'a> = Pin<Box<dyn Future<Output = io::Result<usize>> + 'a>>;
+
+/// A source of bytes that are read into a `LazyBlob` on demand rather than
+/// when the blob is constructed.
+///
+/// A source is split into parts of at most 64KB. The bytes of a part are read
+/// the first time they are loaded and kept in memory for as long as the part
+/// is alive, so parts that never fall within the view of a loaded blob are
+/// never read.
+///
+pub trait BlobDataSource {
+    /// Returns the number of bytes in the source. This must not change for as
+    /// long as the source is part of a blob.
+    ///
+    fn len(&self) -> u64;
+
+    /// Returns true if the source does not contain any bytes.
+    ///
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reads bytes starting at `offset` into `buf` and returns the number of
+    /// bytes that were read, like `std::io::Read::read`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes cannot be read. An error of the kind
+    /// `Interrupted` causes the read to be retried.
+    ///
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> io::Result<usize>;
+
+    /// The asynchronous counterpart of `read_at`, which is used by
+    /// `LazyBlob::load_async`.
+    ///
+    /// The default implementation calls `read_at`. A source that can only be
+    /// read asynchronously should override this and return an error of the
+    /// kind `Unsupported` from `read_at`.
+    ///
+    /// # Errors
+    ///
+    /// See `read_at`.
+    ///
+    fn read_at_async<'a>(&'a self, offset: u64, buf: &'a mut [u8]) -> ReadAtFuture<'a> {
+        Box::pin(future::ready(self.read_at(offset, buf)))
+    }
+}
+
+/// A Blob whose bytes are read from a `BlobDataSource` on demand.
+///
+/// A LazyBlob can be measured and sliced without reading its source. Its
+/// bytes are only accessed through methods that return the errors of the
+/// source. `load` reads the parts in view into a Blob, which never fails to
+/// access its bytes, and `try_text` and `try_to_vec` are shorthands for it.
+/// `reader` reads the parts one at a time as they are reached instead.
+///
+/// Each part is read at most once, even if it is loaded by more than one
+/// slice. The blobs returned by `load` share the bytes that have been read
+/// rather than copying them.
+///
+#[derive(Clone)]
+pub struct LazyBlob {
+    parts: Rc<[LazyPart]>,
+
+    /// The sum of the length of each part.
+    len: usize,
+
+    opts: BlobOptions,
+    view: Option<(usize, usize)>,
+}
+
+/// A range of a source and the bytes that have been read from it.
+///
+struct LazyPart {
+    source: Rc<dyn BlobDataSource>,
+
+    /// The offset of the first byte of the range within the source.
+    offset: u64,
+
+    /// The length of the range.
+    len: usize,
+
+    /// The bytes of the range, once they have been read. They are stored as a
+    /// part so that the blobs returned by `load` can share them.
+    bytes: OnceCell<Part>,
+}
+
+impl LazyPart {
+    /// Returns the bytes of self, reading them from the source if they have
+    /// not been read yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error produced by the source. The bytes are read again the
+    /// next time they are loaded.
+    ///
+    fn load(&self) -> io::Result<&Part> {
+        if let Some(part) = self.bytes.get() {
+            return Ok(part);
+        }
+
+        let mut bytes = vec![0; self.len];
+        let mut filled = 0;
+
+        while filled < self.len {
+            let result = self
+                .source
+                .read_at(self.offset + filled as u64, &mut bytes[filled..]);
+
+            filled += read_len(result)?;
+        }
+
+        Ok(self.bytes.get_or_init(|| Part::from(bytes)))
+    }
+
+    /// The asynchronous counterpart of `load`, which reads the bytes of self
+    /// with `read_at_async`.
+    ///
+    async fn load_async(&self) -> io::Result<&Part> {
+        if let Some(part) = self.bytes.get() {
+            return Ok(part);
+        }
+
+        let mut bytes = vec![0; self.len];
+        let mut filled = 0;
+
+        while filled < self.len {
+            let result = self
+                .source
+                .read_at_async(self.offset + filled as u64, &mut bytes[filled..])
+                .await;
+
+            filled += read_len(result)?;
+        }
+
+        Ok(self.bytes.get_or_init(|| Part::from(bytes)))
+    }
+}
+
+impl PartLen for LazyPart {
+    #[inline]
+    fn part_len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Returns the number of bytes that a single read of a source filled, or 0 if
+/// the read was interrupted and should be retried.
+///
+fn read_len(result: io::Result<usize>) -> io::Result<usize> {
+    match result {
+        Ok(0) => Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "blob data source ended before its length",
+        )),
+        Ok(n) => Ok(n),
+        Err(error) if error.kind() == io::ErrorKind::Interrupted => Ok(0),
+        Err(error) => Err(error),
+    }
+}
+
+impl LazyBlob {
+    /// The size of the bytes in view of self.
+    ///
+    #[inline]
+    pub fn size(&self) -> usize {
+        match self.view {
+            Some((from, to)) => to.saturating_sub(from),
+            None => self.len,
+        }
+    }
+
+    /// Returns a reference to the Content-Type string of self, or an empty
+    /// string if self does not have a Content-Type.
+    ///
+    #[inline]
+    pub fn ty(&self) -> &str {
+        self.opts.ty.as_deref().unwrap_or_default()
+    }
+
+    /// Create a new LazyBlob instance from `start` to `end` and an optional
+    /// Content-Type argument, without reading the source.
+    ///
+    /// The indices are resolved like those of `Blob::slice`.
+    ///
+    pub fn slice(&self, start: i64, end: Option<i64>, ty: Option<String>) -> Self {
+        let (start, end) = resolve_range(start, end, self.size());
+        let (offset, _) = self.range();
+
+        Self {
+            parts: Rc::clone(&self.parts),
+            len: self.len,
+            opts: BlobOptions::new(self.opts.endings, ty.map(Box::from))
+                .with_strip_bom(self.opts.strip_bom),
+            view: Some((offset + start, offset + end)),
+        }
+    }
+
+    /// Reads every part in view of self that has not been read yet and returns
+    /// a Blob that contains the bytes in view, with the same options as self.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error produced by the source. Parts that were read
+    /// before the error are kept, and the rest are read again by the next
+    /// call.
+    ///
+    pub fn load(&self) -> io::Result<Blob> {
+        let mut cursor = Cursor::new(self.range());
+        let mut data = Vec::new();
+
+        while let Some((index, start, end)) = cursor.next_range(&self.parts) {
+            data.push(self.parts[index].load()?.slice(start, end));
+        }
+
+        Ok(Blob::from_data(data.into(), self.opts.clone()))
+    }
+
+    /// The asynchronous counterpart of `load`, which reads the source with
+    /// `read_at_async`.
+    ///
+    /// # Errors
+    ///
+    /// See `load`.
+    ///
+    pub async fn load_async(&self) -> io::Result<Blob> {
+        let mut cursor = Cursor::new(self.range());
+        let mut data = Vec::new();
+
+        while let Some((index, start, end)) = cursor.next_range(&self.parts) {
+            data.push(self.parts[index].load_async().await?.slice(start, end));
+        }
+
+        Ok(Blob::from_data(data.into(), self.opts.clone()))
+    }
+
+    /// Loads self and returns the text contained in the loaded Blob.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error produced by the source, or an error of the kind
+    /// `InvalidData` if the bytes are not valid UTF-8.
+    ///
+    pub async fn try_text(&self) -> io::Result<String> {
+        Ok(self.load_async().await?.text().await?)
+    }
+
+    /// Loads self and returns a copy of the bytes contained in the loaded
+    /// Blob.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error produced by the source.
+    ///
+    pub fn try_to_vec(&self) -> io::Result<Vec<u8>> {
+        self.load().map(Blob::into_vec)
+    }
+
+    /// Returns a reader over the bytes in view of self that reads the source
+    /// one part at a time. See `LazyBlobReader` for details.
+    ///
+    #[inline]
+    pub fn reader(&self) -> LazyBlobReader<'_> {
+        LazyBlobReader {
+            blob: self,
+            cursor: Cursor::new(self.range()),
+            chunk: &[],
+        }
+    }
+
+    /// The absolute range of bytes in self.parts that are contained in self.
+    ///
+    #[inline]
+    fn range(&self) -> (usize, usize) {
+        self.view.unwrap_or((0, self.len))
+    }
+}
+
+/// A reader over the bytes contained in a LazyBlob.
+///
+/// Unlike `load`, which reads every part in view up front, each part is read
+/// from the source when the reader reaches it, so reading the start of a large
+/// blob only reads the parts that the start falls within. Parts that have been
+/// read are kept by the blob.
+///
+/// An error produced by the source is returned by `read` or `fill_buf`, and
+/// the same part is read again by the next call.
+///
+#[derive(Debug)]
+pub struct LazyBlobReader<'a> {
+    blob: &'a LazyBlob,
+
+    /// The position of the next part to read within the blob's view.
+    cursor: Cursor,
+
+    /// The bytes of the current part that have not been read yet.
+    chunk: &'a [u8],
+}
+
+impl Read for LazyBlobReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let chunk = self.fill_buf()?;
+        let len = buf.len().min(chunk.len());
+
+        buf[..len].copy_from_slice(&chunk[..len]);
+        self.consume(len);
+
+        Ok(len)
+    }
+}
+
+impl BufRead for LazyBlobReader<'_> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.chunk.is_empty() {
+            let blob = self.blob;
+            let cursor = self.cursor;
+
+            if let Some((index, start, end)) = self.cursor.next_range(&blob.parts) {
+                match blob.parts[index].load() {
+                    Ok(part) => self.chunk = &part[start..end],
+                    Err(error) => {
+                        // Restore the cursor so that the next call reads the
+                        // same part again.
+                        self.cursor = cursor;
+                        return Err(error);
+                    }
+                }
+            }
+        }
+
+        Ok(self.chunk)
+    }
+
+    fn consume(&mut self, amt: usize) {
+        let amt = amt.min(self.chunk.len());
+        self.chunk = &self.chunk[amt..];
+    }
+}
+
+impl Debug for LazyBlob {
+    /// Summarizes the parts of self instead of printing their contents.
+    ///
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let loaded = self
+            .parts
+            .iter()
+            .filter(|part| part.bytes.get().is_some())
+            .count();
+
+        f.debug_struct("LazyBlob")
+            .field("size", &self.size())
+            .field("type", &self.ty())
+            .field("parts", &self.parts.len())
+            .field("loaded", &loaded)
+            .field("view", &self.view)
+            .finish()
+    }
+}
+
+impl Blob {
+    /// Returns a new LazyBlob that reads its bytes from `source` on demand.
+    ///
+    /// Nothing is read from `source` until the blob is loaded. Only the parts
+    /// that overlap the view of the loaded blob are read, so loading a slice
+    /// of a large source does not read the whole source.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `source` does not fit in a `usize`.
+    ///
+    pub fn from_source<S>(source: S, opts: Option<BlobOptions>) -> LazyBlob
+    where
+        S: BlobDataSource + 'static,
+    {
+        let len = usize::try_from(source.len()).expect("blob data source is too large");
+        let source: Rc<dyn BlobDataSource> = Rc::new(source);
+
+        let parts = (0..len)
+            .step_by(READ_CHUNK_SIZE)
+            .map(|offset| LazyPart {
+                source: Rc::clone(&source),
+                offset: offset as u64,
+                len: READ_CHUNK_SIZE.min(len - offset),
+                bytes: OnceCell::new(),
+            })
+            .collect();
+
+        LazyBlob {
+            parts,
+            len,
+            opts: opts.unwrap_or_default(),
+            view: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::io::{self, Read};
+    use std::rc::Rc;
+
+    use super::{BlobDataSource, ReadAtFuture};
+    use crate::reader::READ_CHUNK_SIZE;
+    use crate::{Blob, BlobOptions, LineEndings};
+
+    /// A source of `len` bytes where each byte is its offset modulo 251, which
+    /// records the number of bytes that are read from it.
+    struct Counting {
+        len: u64,
+        read: Rc<Cell<u64>>,
+    }
+
+    impl Counting {
+        fn new(len: u64) -> (Self, Rc<Cell<u64>>) {
+            let read = Rc::new(Cell::new(0));
+            let source = Self {
+                len,
+                read: Rc::clone(&read),
+            };
+
+            (source, read)
+        }
+
+        fn expected(range: std::ops::Range<u64>) -> Vec<u8> {
+            range.map(|offset| (offset % 251) as u8).collect()
+        }
+    }
+
+    impl BlobDataSource for Counting {
+        fn len(&self) -> u64 {
+            self.len
+        }
+
+        fn read_at(&self, offset: u64, buf: &mut [u8]) -> io::Result<usize> {
+            // Return short reads to exercise the retry loop.
+            let len = buf.len().min(1000);
+
+            for (index, byte) in buf[..len].iter_mut().enumerate() {
+                *byte = ((offset + index as u64) % 251) as u8;
+            }
+
+            self.read.set(self.read.get() + len as u64);
+            Ok(len)
+        }
+    }
+
+    /// A source that fails every read.
+    struct Failing;
+
+    impl BlobDataSource for Failing {
+        fn len(&self) -> u64 {
+            10
+        }
+
+        fn read_at(&self, _: u64, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("disk on fire"))
+        }
+    }
+
+    /// A source that can only be read asynchronously.
+    struct AsyncOnly;
+
+    impl BlobDataSource for AsyncOnly {
+        fn len(&self) -> u64 {
+            5
+        }
+
+        fn read_at(&self, _: u64, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::Unsupported.into())
+        }
+
+        fn read_at_async<'a>(&'a self, offset: u64, buf: &'a mut [u8]) -> ReadAtFuture<'a> {
+            Box::pin(async move {
+                let bytes = b"hello".get(offset as usize..).unwrap_or_default();
+                let len = buf.len().min(bytes.len());
+
+                buf[..len].copy_from_slice(&bytes[..len]);
+                Ok(len)
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn from_source() {
+        let (source, read) = Counting::new(200_000);
+        let blob = Blob::from_source(source, None);
+
+        // Constructing, measuring, and slicing the blob never reads it.
+        let slice = blob.slice(70_000, Some(70_010), None);
+
+        assert_eq!(blob.size(), 200_000);
+        assert_eq!(blob.parts.len(), 4);
+        assert_eq!(slice.size(), 10);
+        assert_eq!(read.get(), 0);
+
+        // Only the part that overlaps the slice is read, and only once.
+        assert_eq!(
+            slice.try_to_vec().unwrap(),
+            Counting::expected(70_000..70_010)
+        );
+        assert_eq!(
+            slice.try_to_vec().unwrap(),
+            Counting::expected(70_000..70_010)
+        );
+        assert_eq!(read.get(), READ_CHUNK_SIZE as u64);
+
+        assert_eq!(blob.try_to_vec().unwrap(), Counting::expected(0..200_000));
+        assert_eq!(read.get(), 200_000);
+    }
+
+    #[tokio::test]
+    async fn from_source_text() {
+        let (source, _) = Counting::new(100);
+        let opts = BlobOptions::new(LineEndings::Transparent, Some("text/plain".into()));
+        let blob = Blob::from_source(source, Some(opts)).slice(65, Some(91), None);
+        let expected = String::from_utf8(Counting::expected(65..91)).unwrap();
+
+        assert_eq!(blob.try_text().await.unwrap(), expected);
+        assert_eq!(blob.load().unwrap(), Blob::from(expected));
+
+        let (source, _) = Counting::new(0);
+        let blob = Blob::from_source(source, None);
+
+        assert_eq!(blob.size(), 0);
+        assert_eq!(blob.try_text().await.unwrap(), "");
+    }
+
+    #[test]
+    fn load_shared() {
+        let (source, _) = Counting::new(100);
+        let opts = BlobOptions::new(LineEndings::Transparent, Some("text/plain".into()));
+        let blob = Blob::from_source(source, Some(opts));
+
+        // Loaded blobs share the bytes that were read and keep the options of
+        // the lazy blob.
+        let first = blob.load().unwrap();
+        let second = blob.slice(10, Some(20), None).load().unwrap();
+
+        assert_eq!(first.ty(), "text/plain");
+        assert_eq!(second.ty(), "");
+        assert_eq!(second.to_vec(), Counting::expected(10..20));
+        assert!(std::sync::Arc::ptr_eq(
+            &first.data[0].bytes,
+            &second.data[0].bytes
+        ));
+    }
+
+    #[tokio::test]
+    async fn from_source_error() {
+        let blob = Blob::from_source(Failing, None);
+
+        assert_eq!(blob.size(), 10);
+        assert_eq!(blob.load().unwrap_err().to_string(), "disk on fire");
+        assert_eq!(blob.try_to_vec().unwrap_err().to_string(), "disk on fire");
+        assert_eq!(
+            blob.try_text().await.unwrap_err().to_string(),
+            "disk on fire"
+        );
+
+        // A failed part is read again the next time it is loaded.
+        assert_eq!(blob.load().unwrap_err().to_string(), "disk on fire");
+
+        // An empty view never reads the source.
+        assert_eq!(blob.slice(4, Some(4), None).try_to_vec().unwrap(), b"");
+    }
+
+    #[test]
+    fn reader() {
+        let (source, read) = Counting::new(200_000);
+        let blob = Blob::from_source(source, None);
+        let slice = blob.slice(70_000, Some(140_000), None);
+        let mut reader = slice.reader();
+
+        // Reading the start of the slice only reads the part that it falls
+        // within.
+        let mut buf = [0; 10];
+
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(buf[..], Counting::expected(70_000..70_010));
+        assert_eq!(read.get(), READ_CHUNK_SIZE as u64);
+
+        // The next part is read when the reader reaches it.
+        let mut rest = Vec::new();
+
+        reader.read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, Counting::expected(70_010..140_000));
+        assert_eq!(read.get(), 2 * READ_CHUNK_SIZE as u64);
+
+        // Parts that were read by the reader are kept by the blob.
+        assert_eq!(
+            slice.try_to_vec().unwrap(),
+            Counting::expected(70_000..140_000)
+        );
+        assert_eq!(read.get(), 2 * READ_CHUNK_SIZE as u64);
+    }
+
+    #[test]
+    fn reader_error() {
+        let blob = Blob::from_source(Failing, None);
+        let mut reader = blob.reader();
+        let mut buf = [0; 4];
+
+        // The error is returned again by the next read.
+        assert_eq!(
+            reader.read(&mut buf).unwrap_err().to_string(),
+            "disk on fire"
+        );
+        assert_eq!(
+            reader.read(&mut buf).unwrap_err().to_string(),
+            "disk on fire"
+        );
+
+        // An empty view never reads the source.
+        assert_eq!(
+            blob.slice(4, Some(4), None)
+                .reader()
+                .read(&mut buf)
+                .unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn load_async() {
+        let blob = Blob::from_source(AsyncOnly, None);
+
+        assert_eq!(blob.load().unwrap_err().kind(), io::ErrorKind::Unsupported);
+        assert_eq!(blob.load_async().await.unwrap().to_vec(), b"hello");
+        assert_eq!(
+            blob.slice(1, Some(-1), None).try_text().await.unwrap(),
+            "ell"
+        );
+
+        // Parts that were read asynchronously are not read again.
+        assert_eq!(blob.try_to_vec().unwrap(), b"hello");
+
+        // The default implementation reads the source synchronously.
+        let (source, read) = Counting::new(10);
+        let blob = Blob::from_source(source, None);
+
+        assert_eq!(
+            blob.load_async().await.unwrap().to_vec(),
+            Counting::expected(0..10)
+        );
+        assert_eq!(read.get(), 10);
+    }
+}
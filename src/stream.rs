@@ -0,0 +1,260 @@
+#[cfg(any(feature = "axum", feature = "reqwest"))]
+use std::convert::Infallible;
+use std::future;
+use std::pin::{pin, Pin};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures_core::Stream;
+
+use crate::part::Part;
+use crate::{Blob, BlobError, BlobOptions, Cursor};
+
+/// A stream of the bytes contained in a Blob, in chunks of at most a fixed
+/// size.
+///
+/// Each chunk is a `Bytes` that shares the buffer of a single part of the
+/// original blob, so no bytes are copied. Parts are never merged, so a part
+/// that is smaller than the chunk size is yielded as is.
+///
+/// The stream holds a reference to the parts of the blob rather than the blob
+/// itself. It is `Send` and can outlive the blob it was created from.
+///
+#[derive(Debug)]
+pub struct BlobChunks {
+    data: Arc<[Part]>,
+
+    /// The position of the next part to yield within the blob's view.
+    cursor: Cursor,
+
+    /// The maximum length of a chunk.
+    chunk_size: usize,
+
+    /// The index, start, and end of the remainder of a part that is longer
+    /// than the chunk size.
+    pending: Option<(usize, usize, usize)>,
+}
+
+impl BlobChunks {
+    fn new(blob: &Blob, chunk_size: usize) -> Self {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+
+        Self {
+            data: Arc::clone(&blob.data),
+            cursor: Cursor::new(blob.range()),
+            chunk_size,
+            pending: None,
+        }
+    }
+
+    fn next_chunk(&mut self) -> Option<Bytes> {
+        let (index, start, end) = match self.pending.take() {
+            Some(range) => range,
+            None => self.cursor.next_range(&self.data)?,
+        };
+
+        let split = end.min(start.saturating_add(self.chunk_size));
+
+        if split < end {
+            self.pending = Some((index, split, end));
+        }
+
+        Some(self.data[index].slice(start, split).into_bytes())
+    }
+}
+
+impl Stream for BlobChunks {
+    type Item = Bytes;
+
+    fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Bytes>> {
+        // The bytes are already in memory, so the next chunk is always ready.
+        Poll::Ready(self.next_chunk())
+    }
+}
+
+impl Blob {
+    /// Returns a `Stream` of the bytes contained in self, in chunks of at most
+    /// `chunk_size` bytes.
+    ///
+    /// This is the native counterpart of the `ReadableStream` returned by
+    /// `stream`. See `BlobChunks` for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    pub fn stream_chunks(&self, chunk_size: usize) -> BlobChunks {
+        BlobChunks::new(self, chunk_size)
+    }
+
+    /// Returns a `Stream` of the parts in view of self for the streaming body
+    /// of an HTTP response or request.
+    ///
+    /// Parts are yielded whole and without copying, since the body is not
+    /// subject to a chunk size.
+    ///
+    #[cfg(any(feature = "axum", feature = "reqwest"))]
+    pub(crate) fn body_stream(
+        &self,
+    ) -> impl Stream<Item = Result<Bytes, Infallible>> + Send + 'static {
+        futures_util::StreamExt::map(self.stream_chunks(usize::MAX), Ok)
+    }
+
+    /// Collects the chunks produced by `stream` into a new Blob.
+    ///
+    /// Each chunk is stored as a separate part rather than concatenated. If
+    /// `limit` is provided, collection stops as soon as the total size of the
+    /// chunks exceeds `limit` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error produced by `stream`. If the chunks exceed
+    /// `limit` bytes, `BlobError::TooLarge` is converted into `E` and
+    /// returned.
+    ///
+    pub async fn from_stream<S, B, E>(
+        stream: S,
+        limit: Option<usize>,
+        opts: Option<BlobOptions>,
+    ) -> Result<Self, E>
+    where
+        S: Stream<Item = Result<B, E>>,
+        B: Into<Vec<u8>>,
+        E: From<BlobError>,
+    {
+        let limit = limit.unwrap_or(usize::MAX);
+        let mut stream = pin!(stream);
+        let mut parts = Vec::new();
+        let mut size = 0usize;
+
+        while let Some(chunk) = future::poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+            let chunk = chunk?.into();
+
+            size = size.saturating_add(chunk.len());
+
+            if size > limit {
+                return Err(BlobError::TooLarge { limit }.into());
+            }
+
+            parts.push(chunk);
+        }
+
+        Ok(Self::new(parts, opts))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use futures::executor::block_on;
+    use futures::stream::{self, StreamExt};
+
+    use crate::{Blob, BlobError};
+
+    fn chunks(blob: &Blob, chunk_size: usize) -> Vec<Vec<u8>> {
+        block_on(
+            blob.stream_chunks(chunk_size)
+                .map(|chunk| chunk.to_vec())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn chunk_sizes() {
+        let blob = Blob::new(vec![&b"ABC"[..], b"", b"DEFG", b"HI"], None);
+
+        let expected: Vec<_> = b"ABCDEFGHI".iter().map(|byte| vec![*byte]).collect();
+        assert_eq!(chunks(&blob, 1), expected);
+
+        // A chunk size equal to the length of a part yields that part as is.
+        assert_eq!(chunks(&blob, 3), [&b"ABC"[..], b"DEF", b"G", b"HI"]);
+        assert_eq!(chunks(&blob, 4), [&b"ABC"[..], b"DEFG", b"HI"]);
+
+        // Parts are never merged, even if the chunk size is larger than the
+        // whole blob.
+        assert_eq!(chunks(&blob, 100), [&b"ABC"[..], b"DEFG", b"HI"]);
+    }
+
+    #[test]
+    fn chunk_view() {
+        let blob = Blob::new(vec![&b"ABCD"[..], b"EFGH", b"IJKL"], None);
+        let slice = blob.slice(2, Some(-1), None);
+
+        assert_eq!(chunks(&slice, 3), [&b"CD"[..], b"EFG", b"H", b"IJK"]);
+        assert!(chunks(&Blob::empty(), 1).is_empty());
+        assert!(chunks(&blob.slice(4, Some(4), None), 1).is_empty());
+    }
+
+    #[test]
+    fn chunk_shared() {
+        let blob = Blob::new(vec![&b"ABCD"[..], b"EFGH"], None);
+        let stream = blob.stream_chunks(2);
+
+        // The stream can be moved to another thread.
+        let chunks: Vec<_> = std::thread::spawn(|| block_on(stream.collect()))
+            .join()
+            .unwrap();
+
+        assert_eq!(chunks.len(), 4);
+
+        // Each chunk shares the buffer of the part it was cut from.
+        for (chunk, (part, start)) in chunks.iter().zip([(0, 0), (0, 2), (1, 0), (1, 2)]) {
+            assert_eq!(chunk.as_ptr(), blob.data[part][start..].as_ptr());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn chunk_size_zero() {
+        Blob::empty().stream_chunks(0);
+    }
+
+    #[test]
+    fn from_stream() {
+        let chunks = vec![Ok::<_, io::Error>(&b"ABC"[..]), Ok(b""), Ok(b"DEFG")];
+        let blob = block_on(Blob::from_stream(stream::iter(chunks), None, None)).unwrap();
+
+        // Each chunk is stored as a separate part.
+        assert_eq!(blob.to_vec(), b"ABCDEFG");
+        assert_eq!(blob.data.len(), 3);
+
+        // Collecting the chunks of a blob round trips.
+        let chunks = blob
+            .stream_chunks(2)
+            .map(|chunk| Ok::<_, io::Error>(chunk.to_vec()));
+        let collected = block_on(Blob::from_stream(chunks, None, None)).unwrap();
+
+        assert_eq!(collected, blob);
+        assert_eq!(collected.data.len(), 4);
+    }
+
+    #[test]
+    fn from_stream_error() {
+        let chunks = vec![
+            Ok(&b"ABC"[..]),
+            Err(io::Error::other("connection reset")),
+            Ok(b"DEFG"),
+        ];
+
+        let error = block_on(Blob::from_stream(stream::iter(chunks), None, None)).unwrap_err();
+        assert_eq!(error.to_string(), "connection reset");
+    }
+
+    #[test]
+    fn from_stream_limit() {
+        let chunks = || stream::iter(vec![Ok::<_, BlobError>(&b"ABC"[..]), Ok(b"DEFG")]);
+
+        assert_eq!(
+            block_on(Blob::from_stream(chunks(), Some(7), None))
+                .unwrap()
+                .size(),
+            7
+        );
+        assert_eq!(
+            block_on(Blob::from_stream(chunks(), Some(6), None)),
+            Err(BlobError::TooLarge { limit: 6 })
+        );
+    }
+}
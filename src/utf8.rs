@@ -0,0 +1,264 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::Blob;
+
+/// An error that reports the position of the first byte of a blob that is not
+/// part of a valid UTF-8 sequence.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Utf8ErrorAt {
+    offset: usize,
+}
+
+impl Utf8ErrorAt {
+    /// Returns the offset of the first invalid byte, relative to the start of
+    /// the blob. Every byte before the offset is valid UTF-8.
+    ///
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Display for Utf8ErrorAt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid UTF-8 sequence at offset {}", self.offset)
+    }
+}
+
+impl Error for Utf8ErrorAt {}
+
+impl Blob {
+    /// Returns true if the bytes contained in self are a valid UTF-8 sequence.
+    ///
+    /// See `validate_utf8` for details.
+    ///
+    #[inline]
+    pub fn is_valid_utf8(&self) -> bool {
+        self.validate_utf8().is_ok()
+    }
+
+    /// Validates that the bytes contained in self are a valid UTF-8 sequence
+    /// without coalescing them.
+    ///
+    /// Each part in view is validated in place. A sequence that straddles the
+    /// boundary between two parts is reassembled from at most 4 bytes. A
+    /// sequence that is cut short by the end of the view is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns the offset of the first byte of the first invalid sequence.
+    ///
+    pub fn validate_utf8(&self) -> Result<(), Utf8ErrorAt> {
+        self.for_each_str(|_| {})
+    }
+
+    /// Decodes the bytes contained in self into a String one part at a time,
+    /// rather than coalescing them into a buffer first.
+    ///
+    /// The only allocation is the String itself. Returns `None` if the bytes
+    /// are not a valid UTF-8 sequence.
+    ///
+    pub(crate) fn decode_utf8(&self) -> Option<String> {
+        let mut text = String::with_capacity(self.size());
+
+        self.for_each_str(|valid| text.push_str(valid)).ok()?;
+        Some(text)
+    }
+
+    /// Calls `f` with each run of valid UTF-8 in view, in order, until the
+    /// first invalid sequence. See `validate_utf8` for details.
+    ///
+    fn for_each_str(&self, mut f: impl FnMut(&str)) -> Result<(), Utf8ErrorAt> {
+        // The offset of the start of the current chunk within self.
+        let mut offset = 0;
+
+        // The bytes of an incomplete sequence at the end of the previous chunk
+        // and the offset that the sequence starts at.
+        let mut carry = [0; 4];
+        let mut carried = 0;
+        let mut carry_start = 0;
+
+        for chunk in self.chunks() {
+            let mut rest = chunk;
+
+            // Complete the sequence that was carried over from the previous
+            // chunk one byte at a time.
+            while carried > 0 {
+                let Some((&byte, tail)) = rest.split_first() else {
+                    break;
+                };
+
+                carry[carried] = byte;
+                carried += 1;
+                rest = tail;
+
+                match std::str::from_utf8(&carry[..carried]) {
+                    Ok(valid) => {
+                        f(valid);
+                        carried = 0;
+                    }
+                    Err(error) if error.error_len().is_some() => {
+                        return Err(Utf8ErrorAt {
+                            offset: carry_start,
+                        });
+                    }
+                    // The sequence is still incomplete.
+                    Err(_) => {}
+                }
+            }
+
+            let start = offset + (chunk.len() - rest.len());
+
+            match std::str::from_utf8(rest) {
+                Ok(valid) => f(valid),
+                Err(error) => {
+                    let valid = error.valid_up_to();
+
+                    // A complete but invalid sequence.
+                    if error.error_len().is_some() {
+                        return Err(Utf8ErrorAt {
+                            offset: start + valid,
+                        });
+                    }
+
+                    // SAFETY: `valid_up_to` is the length of the longest
+                    // prefix of `rest` that is valid UTF-8.
+                    f(unsafe { std::str::from_utf8_unchecked(&rest[..valid]) });
+
+                    // An incomplete sequence at the end of the chunk. It is
+                    // never longer than 3 bytes.
+                    let tail = &rest[valid..];
+
+                    carry[..tail.len()].copy_from_slice(tail);
+                    carried = tail.len();
+                    carry_start = start + valid;
+                }
+            }
+
+            offset += chunk.len();
+        }
+
+        if carried > 0 {
+            return Err(Utf8ErrorAt {
+                offset: carry_start,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Utf8ErrorAt;
+    use crate::Blob;
+
+    fn offset(blob: &Blob) -> Option<usize> {
+        blob.validate_utf8().err().map(|error| error.offset())
+    }
+
+    #[test]
+    fn valid() {
+        // "a\u{20AC}b\u{1F600}" split at every possible position.
+        let text = "a\u{20AC}b\u{1F600}".as_bytes();
+
+        for split in 0..=text.len() {
+            let blob = Blob::new(vec![&text[..split], &text[split..]], None);
+            assert!(blob.is_valid_utf8(), "split at {split}");
+        }
+
+        // One byte per part.
+        assert!(Blob::new(text.chunks(1), None).is_valid_utf8());
+        assert!(Blob::empty().is_valid_utf8());
+    }
+
+    #[test]
+    fn invalid() {
+        // An invalid byte in the middle of a part.
+        let blob = Blob::new(vec![&b"abc"[..], b"de\xFFf"], None);
+        assert_eq!(offset(&blob), Some(5));
+
+        // A sequence that straddles a boundary and is invalid in the next part.
+        let blob = Blob::new(vec![&b"ab\xE2\x82"[..], b"Ac"], None);
+        assert_eq!(offset(&blob), Some(2));
+
+        // A sequence that is carried across an empty part.
+        let blob = Blob::new(vec![&b"\xF0"[..], b"", b"\x9F", b"\x98\x80!"], None);
+        assert_eq!(offset(&blob), None);
+
+        // A sequence at the end of the blob that is incomplete.
+        let blob = Blob::new(vec![&b"ok"[..], b"\xF0\x9F"], None);
+        assert_eq!(offset(&blob), Some(2));
+        assert!(!blob.is_valid_utf8());
+    }
+
+    #[test]
+    fn matches_std() {
+        let inputs: [&[u8]; 6] = [
+            b"plain ascii",
+            "\u{e9}\u{20AC}\u{1F600}".as_bytes(),
+            b"\xE2\x82\xACok\xE2\x28\xA1",
+            b"ok\xF0\x9F\x98",
+            b"\xC0\xAF overlong",
+            b"\xED\xA0\x80 surrogate",
+        ];
+
+        for input in inputs {
+            let expected = std::str::from_utf8(input)
+                .err()
+                .map(|error| error.valid_up_to());
+
+            for split in 0..=input.len() {
+                let blob = Blob::new(vec![&input[..split], &input[split..]], None);
+                assert_eq!(offset(&blob), expected, "{input:?} split at {split}");
+            }
+        }
+    }
+
+    #[test]
+    fn decode() {
+        let text = "a\u{20AC}b\u{1F600}";
+        let bytes = text.as_bytes();
+
+        // One byte per part carries every multi-byte sequence across parts.
+        let blob = Blob::new(bytes.chunks(1), None);
+        let decoded = blob.decode_utf8().unwrap();
+
+        assert_eq!(decoded, text);
+        assert_eq!(decoded.capacity(), bytes.len());
+
+        // Only the bytes in view are decoded.
+        assert_eq!(
+            blob.slice(1, Some(4), None).decode_utf8().unwrap(),
+            "\u{20AC}"
+        );
+        assert_eq!(Blob::empty().decode_utf8().unwrap(), "");
+
+        // Invalid and incomplete sequences are rejected.
+        assert_eq!(blob.slice(0, Some(3), None).decode_utf8(), None);
+        assert_eq!(
+            Blob::new(vec![&b"ab\xE2\x82"[..], b"Ac"], None).decode_utf8(),
+            None
+        );
+    }
+
+    #[test]
+    fn view_edges() {
+        let blob = Blob::from("a\u{20AC}b");
+
+        // Views that cut the 3-byte sequence short at either end.
+        assert_eq!(offset(&blob.slice(0, Some(3), None)), Some(1));
+        assert_eq!(offset(&blob.slice(2, None, None)), Some(0));
+        assert_eq!(offset(&blob.slice(1, Some(4), None)), None);
+
+        // The offset is relative to the view, not the underlying data.
+        let blob = Blob::new(vec![&b"xyz"[..], b"ab\xFF"], None).slice(2, None, None);
+        assert_eq!(blob.validate_utf8(), Err(Utf8ErrorAt { offset: 3 }));
+        assert_eq!(
+            blob.validate_utf8().unwrap_err().to_string(),
+            "invalid UTF-8 sequence at offset 3"
+        );
+    }
+}
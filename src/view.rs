@@ -0,0 +1,152 @@
+use crate::part::Part;
+use crate::{resolve_range, Blob, Cursor};
+
+/// A borrowed view of a range of the bytes contained in a Blob.
+///
+/// Unlike a slice, a view does not clone the reference counted data of the
+/// blob it was created from, so creating one never touches a reference count.
+/// This makes views suitable for short-lived scans in hot loops.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct BlobView<'a> {
+    data: &'a [Part],
+
+    /// The absolute start and end of the view within `data`.
+    from: usize,
+    to: usize,
+}
+
+impl<'a> BlobView<'a> {
+    /// The size of the view in bytes.
+    ///
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.to.saturating_sub(self.from)
+    }
+
+    /// Returns a new view of self from `start` to `end`.
+    ///
+    /// The indices are resolved relative to self with the same semantics as
+    /// `Blob::slice`.
+    ///
+    pub fn slice(&self, start: i64, end: Option<i64>) -> BlobView<'a> {
+        let (start, end) = resolve_range(start, end, self.size());
+
+        Self {
+            data: self.data,
+            from: self.from + start,
+            to: self.from + end,
+        }
+    }
+
+    /// Returns a reference to the bytes contained in self if they are stored
+    /// contiguously in a single part.
+    ///
+    pub fn as_contiguous(&self) -> Option<&'a [u8]> {
+        let mut chunks = self.chunks();
+
+        // An empty view is trivially contiguous.
+        let first = chunks.next().unwrap_or_default();
+
+        match chunks.next() {
+            Some(_) => None,
+            None => Some(first),
+        }
+    }
+
+    /// Returns an iterator over the bytes contained in self.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = u8> + 'a {
+        self.chunks().flat_map(|chunk| chunk.iter().copied())
+    }
+
+    /// Returns a copy of the bytes contained in self as an owned `Vec<u8>`.
+    ///
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut buffer = Vec::with_capacity(self.size());
+
+        for chunk in self.chunks() {
+            buffer.extend_from_slice(chunk);
+        }
+
+        buffer
+    }
+
+    /// Returns an iterator over the sub-slice of each part that overlaps self.
+    ///
+    fn chunks(&self) -> impl Iterator<Item = &'a [u8]> {
+        let data = self.data;
+        let mut cursor = Cursor::new((self.from, self.to));
+
+        std::iter::from_fn(move || cursor.next_chunk(data))
+    }
+}
+
+impl Blob {
+    /// Returns a view of self from `start` to `end` that borrows the data of
+    /// self.
+    ///
+    /// The indices are resolved with the same semantics as `slice`. See
+    /// `BlobView` for details.
+    ///
+    pub fn view(&self, start: i64, end: Option<i64>) -> BlobView<'_> {
+        let (start, end) = resolve_range(start, end, self.size());
+        let (offset, _) = self.range();
+
+        BlobView {
+            data: &self.data,
+            from: offset + start,
+            to: offset + end,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::Blob;
+
+    #[test]
+    fn to_vec() {
+        let blob = Blob::new(vec![&b"ABC"[..], b"", b"DEFG", b"HI"], None);
+        let ranges = [
+            (0, None),
+            (2, Some(7)),
+            (3, Some(7)),
+            (-4, None),
+            (5, Some(2)),
+            (100, None),
+        ];
+
+        for (start, end) in ranges {
+            let view = blob.view(start, end);
+            let slice = blob.slice(start, end, None);
+
+            assert_eq!(view.to_vec(), slice.coalesce(), "{start}..{end:?}");
+            assert_eq!(view.size(), slice.size());
+            assert_eq!(view.as_contiguous(), slice.as_contiguous());
+            assert!(view.iter().eq(slice.iter()));
+        }
+
+        // Creating a view does not clone the data of the blob.
+        assert_eq!(Arc::strong_count(&blob.data), 1);
+    }
+
+    #[test]
+    fn nested() {
+        let blob = Blob::new(vec![&b"ABCD"[..], b"EFGH", b"IJKL"], None);
+        let slice = blob.slice(1, Some(-1), None);
+
+        // Views of a slice and of a view address bytes relative to their
+        // parent.
+        let view = slice.view(2, Some(-2));
+        assert_eq!(view.to_vec(), slice.slice(2, Some(-2), None).coalesce());
+        assert_eq!(view.to_vec(), b"DEFGHI");
+
+        let nested = view.slice(1, Some(-1));
+        assert_eq!(nested.to_vec(), b"EFGH");
+        assert_eq!(nested.as_contiguous(), Some(&b"EFGH"[..]));
+        assert_eq!(nested.slice(-10, Some(10)).to_vec(), b"EFGH");
+    }
+}